@@ -19,7 +19,7 @@ pub struct Argument<'fmt> {
     lifetime: PhantomData<&'fmt ()>,
 
     /// The function pointer to `value`'s `Format::format` method
-    formatter: fn(*const c_void, &mut Formatter<'_>),
+    formatter: fn(*const c_void, &mut Formatter<'_, '_>),
 }
 
 impl Clone for Argument<'_> {
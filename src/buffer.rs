@@ -15,7 +15,7 @@ use crate::format_element::{
 use crate::write_with_formatter;
 
 /// A trait for writing or formatting into [FormatElement]-accepting buffers or streams.
-pub trait Buffer {
+pub trait Buffer<'ast> {
     /// Writes a [crate::FormatElement] into this buffer, returning whether the write succeeded.
     ///
     /// # Errors
@@ -64,10 +64,10 @@ pub trait Buffer {
     }
 
     /// Returns the formatting state relevant for this formatting session.
-    fn state(&self) -> &FormatState;
+    fn state(&self) -> &FormatState<'ast>;
 
     /// Returns the mutable formatting state relevant for this formatting session.
-    fn state_mut(&mut self) -> &mut FormatState;
+    fn state_mut(&mut self) -> &mut FormatState<'ast>;
 
     /// Takes a snapshot of the Buffers state, excluding the formatter state.
     fn snapshot(&self) -> BufferSnapshot;
@@ -137,7 +137,7 @@ impl BufferSnapshot {
 }
 
 /// Implements the `[Buffer]` trait for all mutable references of objects implementing [Buffer].
-impl<W: Buffer + ?Sized> Buffer for &mut W {
+impl<'ast, W: Buffer<'ast> + ?Sized> Buffer<'ast> for &mut W {
     fn write_element(&mut self, element: FormatElement) {
         (**self).write_element(element);
     }
@@ -150,11 +150,11 @@ impl<W: Buffer + ?Sized> Buffer for &mut W {
         (**self).write_fmt(args);
     }
 
-    fn state(&self) -> &FormatState {
+    fn state(&self) -> &FormatState<'ast> {
         (**self).state()
     }
 
-    fn state_mut(&mut self) -> &mut FormatState {
+    fn state_mut(&mut self) -> &mut FormatState<'ast> {
         (**self).state_mut()
     }
 
@@ -171,22 +171,22 @@ impl<W: Buffer + ?Sized> Buffer for &mut W {
 ///
 /// The buffer writes all elements into the internal elements buffer.
 #[derive(Debug)]
-pub struct VecBuffer<'a> {
-    state: &'a mut FormatState,
+pub struct VecBuffer<'a, 'ast> {
+    state: &'a mut FormatState<'ast>,
     elements: Vec<FormatElement>,
 }
 
-impl<'a> VecBuffer<'a> {
-    pub fn new(state: &'a mut FormatState) -> Self {
+impl<'a, 'ast> VecBuffer<'a, 'ast> {
+    pub fn new(state: &'a mut FormatState<'ast>) -> Self {
         Self::new_with_vec(state, Vec::new())
     }
 
-    pub fn new_with_vec(state: &'a mut FormatState, elements: Vec<FormatElement>) -> Self {
+    pub fn new_with_vec(state: &'a mut FormatState<'ast>, elements: Vec<FormatElement>) -> Self {
         Self { state, elements }
     }
 
     /// Creates a buffer with the specified capacity
-    pub fn with_capacity(capacity: usize, state: &'a mut FormatState) -> Self {
+    pub fn with_capacity(capacity: usize, state: &'a mut FormatState<'ast>) -> Self {
         Self {
             state,
             elements: Vec::with_capacity(capacity),
@@ -204,7 +204,7 @@ impl<'a> VecBuffer<'a> {
     }
 }
 
-impl Deref for VecBuffer<'_> {
+impl Deref for VecBuffer<'_, '_> {
     type Target = [FormatElement];
 
     fn deref(&self) -> &Self::Target {
@@ -212,13 +212,13 @@ impl Deref for VecBuffer<'_> {
     }
 }
 
-impl DerefMut for VecBuffer<'_> {
+impl DerefMut for VecBuffer<'_, '_> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.elements
     }
 }
 
-impl Buffer for VecBuffer<'_> {
+impl<'ast> Buffer<'ast> for VecBuffer<'_, 'ast> {
     fn write_element(&mut self, element: FormatElement) {
         self.elements.push(element);
     }
@@ -227,11 +227,11 @@ impl Buffer for VecBuffer<'_> {
         self
     }
 
-    fn state(&self) -> &FormatState {
+    fn state(&self) -> &FormatState<'ast> {
         self.state
     }
 
-    fn state_mut(&mut self) -> &mut FormatState {
+    fn state_mut(&mut self) -> &mut FormatState<'ast> {
         self.state
     }
 
@@ -252,18 +252,18 @@ Make sure that you take and restore the snapshot in order and that this snapshot
 }
 
 /// Buffer that allows you inspecting elements as they get written to the formatter.
-pub struct Inspect<'inner, Inspector> {
-    inner: &'inner mut dyn Buffer,
+pub struct Inspect<'inner, 'ast, Inspector> {
+    inner: &'inner mut dyn Buffer<'ast>,
     inspector: Inspector,
 }
 
-impl<'inner, Inspector> Inspect<'inner, Inspector> {
-    fn new(inner: &'inner mut dyn Buffer, inspector: Inspector) -> Self {
+impl<'inner, 'ast, Inspector> Inspect<'inner, 'ast, Inspector> {
+    fn new(inner: &'inner mut dyn Buffer<'ast>, inspector: Inspector) -> Self {
         Self { inner, inspector }
     }
 }
 
-impl<Inspector> Buffer for Inspect<'_, Inspector>
+impl<'ast, Inspector> Buffer<'ast> for Inspect<'_, 'ast, Inspector>
 where
     Inspector: FnMut(&FormatElement),
 {
@@ -276,11 +276,11 @@ where
         self.inner.elements()
     }
 
-    fn state(&self) -> &FormatState {
+    fn state(&self) -> &FormatState<'ast> {
         self.inner.state()
     }
 
-    fn state_mut(&mut self) -> &mut FormatState {
+    fn state_mut(&mut self) -> &mut FormatState<'ast> {
         self.inner.state_mut()
     }
 
@@ -338,8 +338,8 @@ where
 /// # Ok(())
 /// # }
 /// ```
-pub struct RemoveSoftLinesBuffer<'a> {
-    inner: &'a mut dyn Buffer,
+pub struct RemoveSoftLinesBuffer<'a, 'ast> {
+    inner: &'a mut dyn Buffer<'ast>,
 
     /// Caches the interned elements after the soft line breaks have been removed.
     ///
@@ -354,9 +354,9 @@ pub struct RemoveSoftLinesBuffer<'a> {
     conditional_content_stack: Vec<Condition>,
 }
 
-impl<'a> RemoveSoftLinesBuffer<'a> {
+impl<'a, 'ast> RemoveSoftLinesBuffer<'a, 'ast> {
     /// Creates a new buffer that removes the soft line breaks before writing them into `buffer`.
-    pub fn new(inner: &'a mut dyn Buffer) -> Self {
+    pub fn new(inner: &'a mut dyn Buffer<'ast>) -> Self {
         Self {
             inner,
             interned_cache: FxHashMap::default(),
@@ -484,7 +484,7 @@ fn clean_interned(
     }
 }
 
-impl Buffer for RemoveSoftLinesBuffer<'_> {
+impl<'ast> Buffer<'ast> for RemoveSoftLinesBuffer<'_, 'ast> {
     fn write_element(&mut self, element: FormatElement) {
         let mut element_statck = Vec::new();
         element_statck.push(element);
@@ -527,11 +527,11 @@ impl Buffer for RemoveSoftLinesBuffer<'_> {
         self.inner.elements()
     }
 
-    fn state(&self) -> &FormatState {
+    fn state(&self) -> &FormatState<'ast> {
         self.inner.state()
     }
 
-    fn state_mut(&mut self) -> &mut FormatState {
+    fn state_mut(&mut self) -> &mut FormatState<'ast> {
         self.inner.state_mut()
     }
 
@@ -544,10 +544,10 @@ impl Buffer for RemoveSoftLinesBuffer<'_> {
     }
 }
 
-pub trait BufferExtensions: Buffer + Sized {
+pub trait BufferExtensions<'ast>: Buffer<'ast> + Sized {
     /// Returns a new buffer that calls the passed inspector for every element that gets written to the output
     #[must_use]
-    fn inspect<F>(&mut self, inspector: F) -> Inspect<F>
+    fn inspect<F>(&mut self, inspector: F) -> Inspect<'_, 'ast, F>
     where
         F: FnMut(&FormatElement),
     {
@@ -607,7 +607,7 @@ pub trait BufferExtensions: Buffer + Sized {
     }
 }
 
-impl<T> BufferExtensions for T where T: Buffer {}
+impl<'ast, T> BufferExtensions<'ast> for T where T: Buffer<'ast> {}
 
 #[derive(Debug)]
 pub struct Recording<'buf, Buffer> {
@@ -615,9 +615,9 @@ pub struct Recording<'buf, Buffer> {
     buffer: &'buf mut Buffer,
 }
 
-impl<'buf, B> Recording<'buf, B>
+impl<'buf, 'ast, B> Recording<'buf, B>
 where
-    B: Buffer,
+    B: Buffer<'ast>,
 {
     fn new(buffer: &'buf mut B) -> Self {
         Self {
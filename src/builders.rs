@@ -2,9 +2,12 @@ use std::cell::Cell;
 use std::marker::PhantomData;
 use std::num::NonZeroU8;
 
+use oxc_span::Span;
+
 use crate::arguments::{Argument, Arguments};
 use crate::buffer::{Buffer, BufferExtensions, VecBuffer};
 use crate::format::Format;
+use crate::format::comments::is_suppressed;
 use crate::format_element::tag::{Condition, DedentMode, GroupMode, LabelId, Tag::*};
 use crate::format_element::*;
 use crate::formatter::Formatter;
@@ -2314,18 +2317,18 @@ impl<T> std::fmt::Debug for FormatOnce<T> {
 /// Builder to join together a sequence of content.
 /// See [Formatter::join]
 #[must_use = "must eventually call `finish()` on Format builders"]
-pub struct JoinBuilder<'fmt, 'buf, Separator> {
-    fmt: &'fmt mut Formatter<'buf>,
+pub struct JoinBuilder<'fmt, 'buf, 'ast, Separator> {
+    fmt: &'fmt mut Formatter<'buf, 'ast>,
     with: Option<Separator>,
     has_elements: bool,
 }
 
-impl<'fmt, 'buf, Separator> JoinBuilder<'fmt, 'buf, Separator>
+impl<'fmt, 'buf, 'ast, Separator> JoinBuilder<'fmt, 'buf, 'ast, Separator>
 where
     Separator: Format,
 {
     /// Creates a new instance that joins the elements without a separator
-    pub(super) fn new(fmt: &'fmt mut Formatter<'buf>) -> Self {
+    pub(super) fn new(fmt: &'fmt mut Formatter<'buf, 'ast>) -> Self {
         Self {
             fmt,
             has_elements: false,
@@ -2334,7 +2337,7 @@ where
     }
 
     /// Creates a new instance that prints the passed separator between every two entries.
-    pub(super) fn with_separator(fmt: &'fmt mut Formatter<'buf>, with: Separator) -> Self {
+    pub(super) fn with_separator(fmt: &'fmt mut Formatter<'buf, 'ast>, with: Separator) -> Self {
         Self {
             fmt,
             has_elements: false,
@@ -2376,18 +2379,18 @@ where
 /// Builder to join together nodes that ensures that nodes separated by empty lines continue
 /// to be separated by empty lines in the formatted output.
 #[must_use = "must eventually call `finish()` on Format builders"]
-pub struct JoinNodesBuilder<'fmt, 'buf, Separator> {
+pub struct JoinNodesBuilder<'fmt, 'buf, 'ast, Separator> {
     /// The separator to insert between nodes. Either a soft or hard line break
     separator: Separator,
-    fmt: &'fmt mut Formatter<'buf>,
+    fmt: &'fmt mut Formatter<'buf, 'ast>,
     has_elements: bool,
 }
 
-impl<'fmt, 'buf, Separator> JoinNodesBuilder<'fmt, 'buf, Separator>
+impl<'fmt, 'buf, 'ast, Separator> JoinNodesBuilder<'fmt, 'buf, 'ast, Separator>
 where
     Separator: Format,
 {
-    pub(super) fn new(separator: Separator, fmt: &'fmt mut Formatter<'buf>) -> Self {
+    pub(super) fn new(separator: Separator, fmt: &'fmt mut Formatter<'buf, 'ast>) -> Self {
         Self {
             separator,
             fmt,
@@ -2397,9 +2400,16 @@ where
 
     /// Adds a new node with the specified formatted content to the output, respecting any new lines
     /// that appear before the node in the input source.
-    pub fn entry(&mut self, _node: () /*&SyntaxNode*/, content: &dyn Format) {
+    pub fn entry(&mut self, span: Span, content: &dyn Format) {
         if self.has_elements {
-            if get_lines_before(/* TODO: node*/) > 1 {
+            // A suppressed node is printed with its leading comments, so the lines before the
+            // first comment are the lines before the node
+            let start = match self.fmt.context().leading_comments(span).first() {
+                Some(comment) if is_suppressed(span, self.fmt) => comment.span,
+                _ => span,
+            };
+
+            if get_lines_before(start, self.fmt) > 1 {
                 write!(self.fmt, [empty_line()]);
             } else {
                 self.separator.fmt(self.fmt);
@@ -2422,14 +2432,14 @@ where
         content.fmt(self.fmt);
     }
 
-    /// Adds an iterator of entries to the output. Each entry is a `(node, content)` tuple.
+    /// Adds an iterator of entries to the output. Each entry is a `(span, content)` tuple.
     pub fn entries<F, I>(&mut self, entries: I) -> &mut Self
     where
         F: Format,
-        I: IntoIterator<Item = ((), F)>,
+        I: IntoIterator<Item = (Span, F)>,
     {
-        for (node, content) in entries {
-            self.entry(node, &content)
+        for (span, content) in entries {
+            self.entry(span, &content)
         }
 
         self
@@ -2438,40 +2448,36 @@ where
     pub fn finish(&mut self) {}
 }
 
-/// Get the number of line breaks between two consecutive SyntaxNodes in the tree
-pub fn get_lines_before(/*next_node: &SyntaxNode*/) -> usize {
-    0
-    // // Count the newlines in the leading trivia of the next node
-    // if let Some(token) = next_node.first_token() {
-    //     get_lines_before_token(&token)
-    // } else {
-    //     0
-    // }
-}
+/// Get the number of line breaks between a node and the previous token or comment in the source text.
+pub fn get_lines_before(span: Span, f: &Formatter) -> usize {
+    let source_text = f.context().source_text();
+
+    let mut lines = 0;
+    let mut chars = source_text[..span.start as usize].chars().rev().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            // `\r\n` is counted once with the `\r` that comes next in the reversed iteration
+            '\n' if chars.peek() == Some(&'\r') => {}
+            '\n' | '\r' | LINE_SEPARATOR | PARAGRAPH_SEPARATOR => lines += 1,
+            c if c.is_whitespace() => {}
+            // Stop at the first comment or token, the comment printer
+            // will handle newlines between the comment and the node
+            _ => break,
+        }
+    }
 
-pub fn get_lines_before_token(/*token: &SyntaxToken*/) -> usize {
-    0
-    // token
-    //     .leading_trivia()
-    //     .pieces()
-    //     .take_while(|piece| {
-    //         // Stop at the first comment or skipped piece, the comment printer
-    //         // will handle newlines between the comment and the node
-    //         !(piece.is_comments() || piece.is_skipped())
-    //     })
-    //     .filter(|piece| piece.is_newline())
-    //     .count()
+    lines
 }
 
 /// Builder to fill as many elements as possible on a single line.
 #[must_use = "must eventually call `finish()` on Format builders"]
-pub struct FillBuilder<'fmt, 'buf> {
-    fmt: &'fmt mut Formatter<'buf>,
+pub struct FillBuilder<'fmt, 'buf, 'ast> {
+    fmt: &'fmt mut Formatter<'buf, 'ast>,
     empty: bool,
 }
 
-impl<'a, 'buf> FillBuilder<'a, 'buf> {
-    pub(crate) fn new(fmt: &'a mut Formatter<'buf>) -> Self {
+impl<'a, 'buf, 'ast> FillBuilder<'a, 'buf, 'ast> {
+    pub(crate) fn new(fmt: &'a mut Formatter<'buf, 'ast>) -> Self {
         fmt.write_element(FormatElement::Tag(StartFill));
 
         Self { fmt, empty: true }
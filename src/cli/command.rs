@@ -0,0 +1,213 @@
+use std::path::PathBuf;
+
+use bpaf::{OptionParser, Parser, construct, long, positional};
+
+use crate::config::FormatConfig;
+use crate::options::{
+    AmbiguousWidth, ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing, Expand,
+    IndentStyle, IndentWidth, LineEnding, LineWidth, ObjectWrap, QuoteProperties, QuoteStyle,
+    Semicolons, TrailingCommas,
+};
+
+#[derive(Debug, Clone)]
+pub struct FormatCommand {
+    /// Options set on the command line, which take precedence over the configuration files.
+    pub config: FormatConfig,
+    /// Don't look for configuration files.
+    pub no_config: bool,
+    /// Print the IR of the formatted code instead of the code.
+    pub print_ir: bool,
+    pub mode: OutputMode,
+    /// Number of unchanged lines around each change printed with `--diff`.
+    pub diff_context: usize,
+    /// Number of threads used to format files, defaults to the number of cores.
+    pub threads: Option<usize>,
+    /// Path used to infer the source type of the code read from stdin.
+    pub stdin_filepath: Option<PathBuf>,
+    /// Ignore files read in addition to the `.gitignore`, `.prettierignore` and
+    /// `.oxcformatignore` files found in the walked directories.
+    pub ignore_paths: Vec<PathBuf>,
+    /// Only format the files matching these globs.
+    pub include: Vec<String>,
+    /// Don't format the files matching these globs.
+    pub exclude: Vec<String>,
+    /// Files and directories to format. The code is read from stdin if empty.
+    pub paths: Vec<PathBuf>,
+}
+
+/// What to do with the formatted code.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum OutputMode {
+    /// Print the formatted code to stdout.
+    Print,
+    /// Overwrite the files with the formatted code.
+    Write,
+    /// Report the files that aren't formatted and exit with a non-zero code if there are any.
+    Check,
+    /// Print a unified diff of the changes and exit with a non-zero code if there are any.
+    Diff,
+}
+
+pub fn format_command() -> OptionParser<FormatCommand> {
+    let config = format_config();
+    let no_config = long("no-config")
+        .help("Don't look for configuration files")
+        .switch();
+    let print_ir = long("print-ir")
+        .help("Print the IR of the formatted code instead of the code, for debugging")
+        .switch();
+    let mode = output_mode();
+    let diff_context = long("diff-context")
+        .help("Number of unchanged lines around each change printed with --diff")
+        .argument::<usize>("NUMBER")
+        .fallback(3);
+    let threads = long("threads")
+        .help("Number of threads used to format files, defaults to the number of cores")
+        .argument::<usize>("NUMBER")
+        .optional();
+    let stdin_filepath = long("stdin-filepath")
+        .help("Path used to infer the language of the code read from stdin")
+        .argument::<PathBuf>("PATH")
+        .optional();
+    let ignore_paths = long("ignore-path")
+        .help("Read ignore patterns from this file, in addition to the .gitignore, .prettierignore and .oxcformatignore files")
+        .argument::<PathBuf>("PATH")
+        .many();
+    let include = long("include")
+        .help("Only format the files matching this glob")
+        .argument::<String>("GLOB")
+        .many();
+    let exclude = long("exclude")
+        .help("Don't format the files matching this glob")
+        .argument::<String>("GLOB")
+        .many();
+    let paths = positional::<PathBuf>("PATH")
+        .help("Files or directories to format, reads from stdin if none are given")
+        .many();
+
+    construct!(FormatCommand {
+        config,
+        no_config,
+        print_ir,
+        mode,
+        diff_context,
+        threads,
+        stdin_filepath,
+        ignore_paths,
+        include,
+        exclude,
+        paths
+    })
+    .to_options()
+    .descr("Format JavaScript and TypeScript files")
+}
+
+fn output_mode() -> impl Parser<OutputMode> {
+    let write = long("write")
+        .help("Write the formatted code back to the files")
+        .req_flag(OutputMode::Write);
+    let check = long("check")
+        .help("Check that the files are formatted and exit with a non-zero code if not")
+        .req_flag(OutputMode::Check);
+    let diff = long("diff")
+        .help("Print a unified diff of the changes and exit with a non-zero code if there are any")
+        .req_flag(OutputMode::Diff);
+
+    construct!([write, check, diff]).fallback(OutputMode::Print)
+}
+
+fn format_config() -> impl Parser<FormatConfig> {
+    let indent_style = long("indent-style")
+        .help("The indent style: tab or space")
+        .argument::<IndentStyle>("STYLE")
+        .optional();
+    let indent_width = long("indent-width")
+        .help("The number of spaces per indent level")
+        .argument::<IndentWidth>("NUMBER")
+        .optional();
+    let line_ending = long("line-ending")
+        .help("The type of line ending: lf, crlf, cr or auto")
+        .argument::<LineEnding>("ENDING")
+        .optional();
+    let line_width = long("line-width")
+        .help("The maximum width of a line")
+        .argument::<LineWidth>("NUMBER")
+        .optional();
+    let quote_style = long("quote-style")
+        .help("The quotes of strings: double or single")
+        .argument::<QuoteStyle>("STYLE")
+        .optional();
+    let jsx_quote_style = long("jsx-quote-style")
+        .help("The quotes of JSX attributes: double or single")
+        .argument::<QuoteStyle>("STYLE")
+        .optional();
+    let quote_properties = long("quote-properties")
+        .help("When properties in objects are quoted: as-needed or preserve")
+        .argument::<QuoteProperties>("MODE")
+        .optional();
+    let trailing_commas = long("trailing-commas")
+        .help("Where to print trailing commas: all, es5 or none")
+        .argument::<TrailingCommas>("MODE")
+        .optional();
+    let semicolons = long("semicolons")
+        .help("When to print semicolons: always or as-needed")
+        .argument::<Semicolons>("MODE")
+        .optional();
+    let arrow_parentheses = long("arrow-parentheses")
+        .help("When to add parentheses around arrow function parameters: always or as-needed")
+        .argument::<ArrowParentheses>("MODE")
+        .optional();
+    let bracket_spacing = long("bracket-spacing")
+        .help("Whether to insert spaces around brackets in object literals: true or false")
+        .argument::<BracketSpacing>("BOOL")
+        .optional();
+    let bracket_same_line = long("bracket-same-line")
+        .help("Whether to put the `>` of multi-line JSX elements on the last line: true or false")
+        .argument::<BracketSameLine>("BOOL")
+        .optional();
+    let attribute_position = long("attribute-position")
+        .help("The position of JSX attributes: auto or multiline")
+        .argument::<AttributePosition>("MODE")
+        .optional();
+    let expand = long("expand")
+        .help("Whether to expand objects, object types and import/export lists: auto, always or never")
+        .argument::<Expand>("MODE");
+    let object_wrap = long("object-wrap")
+        .help("The Prettier alternative to --expand: preserve (auto) or collapse (never)")
+        .argument::<ObjectWrap>("MODE")
+        .map(Expand::from);
+    let expand = construct!([expand, object_wrap]).optional();
+    let ambiguous_width = long("ambiguous-width")
+        .help("The width of East Asian ambiguous characters like `°` or `→`: narrow or wide")
+        .argument::<AmbiguousWidth>("WIDTH")
+        .optional();
+    let require_pragma = long("require-pragma")
+        .help("Only format files with a @format or @prettier pragma in their top docblock")
+        .req_flag(true)
+        .optional();
+    let insert_pragma = long("insert-pragma")
+        .help("Insert a @format pragma into the top docblock of formatted files")
+        .req_flag(true)
+        .optional();
+
+    construct!(FormatConfig {
+        indent_style,
+        indent_width,
+        line_ending,
+        line_width,
+        quote_style,
+        jsx_quote_style,
+        quote_properties,
+        trailing_commas,
+        semicolons,
+        arrow_parentheses,
+        bracket_spacing,
+        bracket_same_line,
+        attribute_position,
+        expand,
+        ambiguous_width,
+        require_pragma,
+        insert_pragma,
+    })
+    .group_help("Format options:")
+}
@@ -0,0 +1,86 @@
+use std::fmt::Write;
+
+use similar::{ChangeTag, TextDiff};
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const CYAN: &str = "\x1b[36m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+/// Renders the unified diff between the original and the formatted code of `path`, with
+/// `context` unchanged lines around each change. Uses ANSI colors if `color` is `true`.
+pub fn unified_diff(
+    path: &str,
+    original: &str,
+    formatted: &str,
+    context: usize,
+    color: bool,
+) -> String {
+    let paint = |style: &'static str| if color { style } else { "" };
+    let reset = paint(RESET);
+
+    let diff = TextDiff::from_lines(original, formatted);
+    let mut output = String::new();
+
+    let bold = paint(BOLD);
+    let _ = writeln!(output, "{bold}--- {path}{reset}");
+    let _ = writeln!(output, "{bold}+++ {path}{reset}");
+
+    for hunk in diff.unified_diff().context_radius(context).iter_hunks() {
+        let _ = writeln!(output, "{}{}{reset}", paint(CYAN), hunk.header());
+
+        for change in hunk.iter_changes() {
+            let (sign, style) = match change.tag() {
+                ChangeTag::Delete => ('-', paint(RED)),
+                ChangeTag::Insert => ('+', paint(GREEN)),
+                ChangeTag::Equal => (' ', ""),
+            };
+            let line = change.value().strip_suffix('\n').unwrap_or(change.value());
+            let line_reset = if style.is_empty() { "" } else { reset };
+            let _ = writeln!(output, "{style}{sign}{line}{line_reset}");
+
+            if change.missing_newline() {
+                let _ = writeln!(output, "\\ No newline at end of file");
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::unified_diff;
+
+    #[test]
+    fn hunks_and_context() {
+        let original = "a\nb\nc\nd\ne\nf\ng\nh\ni\nj\n";
+        let formatted = "a\nB\nc\nd\ne\nf\ng\nh\nI\nj\n";
+
+        assert_eq!(
+            unified_diff("a.js", original, formatted, 1, false),
+            "--- a.js\n+++ a.js\n@@ -1,3 +1,3 @@\n a\n-b\n+B\n c\n@@ -8,3 +8,3 @@\n h\n-i\n+I\n j\n"
+        );
+        assert_eq!(
+            unified_diff("a.js", original, formatted, 3, false),
+            "--- a.js\n+++ a.js\n@@ -1,10 +1,10 @@\n a\n-b\n+B\n c\n d\n e\n f\n g\n h\n-i\n+I\n j\n"
+        );
+    }
+
+    #[test]
+    fn missing_newline_at_end_of_file() {
+        assert_eq!(
+            unified_diff("a.js", "foo( )", "foo();\n", 3, false),
+            "--- a.js\n+++ a.js\n@@ -1 +1 @@\n-foo( )\n\\ No newline at end of file\n+foo();\n"
+        );
+    }
+
+    #[test]
+    fn colors() {
+        assert_eq!(
+            unified_diff("a.js", "a\n", "b\n", 3, true),
+            "\x1b[1m--- a.js\x1b[0m\n\x1b[1m+++ a.js\x1b[0m\n\x1b[36m@@ -1 +1 @@\x1b[0m\n\x1b[31m-a\x1b[0m\n\x1b[32m+b\x1b[0m\n"
+        );
+    }
+}
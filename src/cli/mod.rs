@@ -0,0 +1,11 @@
+//! The `oxc_formatter` command-line interface.
+
+mod command;
+mod diff;
+mod runner;
+#[cfg(test)]
+mod temp_dir;
+mod walk;
+
+pub use command::{FormatCommand, OutputMode, format_command};
+pub use runner::{EXIT_ERROR, FormatRunner};
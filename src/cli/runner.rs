@@ -0,0 +1,275 @@
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Instant;
+
+use oxc_allocator::Allocator;
+use oxc_span::SourceType;
+use rayon::ThreadPoolBuilder;
+use rayon::prelude::*;
+use rustc_hash::FxHashSet;
+
+use crate::cli::diff::unified_diff;
+use crate::cli::walk::Walk;
+use crate::cli::{FormatCommand, OutputMode};
+use crate::config::{ConfigError, ConfigResolver, FormatConfig};
+use crate::options::FormatOptions;
+use crate::{FormatResult, format_source_with_allocator};
+
+/// Exit code when `--check` or `--diff` find files that aren't formatted.
+const EXIT_UNFORMATTED: u8 = 1;
+/// Exit code when a file can't be read, parsed or written, or the arguments are invalid.
+pub const EXIT_ERROR: u8 = 2;
+
+thread_local! {
+    /// Allocator reused by all files formatted on the thread, reset after each of them.
+    static ALLOCATOR: RefCell<Allocator> = RefCell::new(Allocator::new());
+}
+
+pub struct FormatRunner {
+    command: FormatCommand,
+    resolver: ConfigResolver,
+}
+
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum FileStatus {
+    Unchanged,
+    Changed,
+    Error,
+}
+
+/// Outcome of formatting a single file. Reports are printed once all files are formatted,
+/// so that the output follows the order of the files.
+struct FileReport {
+    status: FileStatus,
+    /// Printed to stdout.
+    output: String,
+    /// Printed to stderr.
+    error: Option<String>,
+}
+
+impl FileReport {
+    fn new(status: FileStatus, output: String) -> Self {
+        Self {
+            status,
+            output,
+            error: None,
+        }
+    }
+
+    fn error(path: &Path, error: impl Display) -> Self {
+        Self::failed(format!("{}: {error}", path.display()))
+    }
+
+    fn failed(error: String) -> Self {
+        Self {
+            status: FileStatus::Error,
+            output: String::new(),
+            error: Some(error),
+        }
+    }
+}
+
+impl FormatRunner {
+    pub fn new(command: FormatCommand) -> Self {
+        Self {
+            command,
+            resolver: ConfigResolver::new(),
+        }
+    }
+
+    pub fn run(self) -> ExitCode {
+        if let Some(threads) = self.command.threads {
+            // Only fails if the global pool was already initialized
+            let _ = ThreadPoolBuilder::new().num_threads(threads).build_global();
+        }
+
+        let start = Instant::now();
+        let reports = if self.command.paths.is_empty() {
+            vec![self.format_stdin()]
+        } else {
+            let walk = match Walk::new(&self.command) {
+                Ok(walk) => walk,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::from(EXIT_ERROR);
+                }
+            };
+            walk.collect()
+                .par_iter()
+                .map(|path| self.format_file(path))
+                .collect()
+        };
+        let elapsed = start.elapsed();
+
+        // An invalid configuration file fails all the files using it, only report it once
+        let mut printed_errors = FxHashSet::default();
+        let mut stdout = io::stdout().lock();
+        for report in &reports {
+            let _ = stdout.write_all(report.output.as_bytes());
+            if let Some(error) = &report.error
+                && printed_errors.insert(error)
+            {
+                eprintln!("{error}");
+            }
+        }
+        let _ = stdout.flush();
+
+        let count = |status| {
+            reports
+                .iter()
+                .filter(|report| report.status == status)
+                .count()
+        };
+        let changed = count(FileStatus::Changed);
+        let unchanged = count(FileStatus::Unchanged);
+        let failed = count(FileStatus::Error);
+
+        if !self.command.paths.is_empty() {
+            let files = reports.len();
+            let files = if files == 1 {
+                "1 file".to_string()
+            } else {
+                format!("{files} files")
+            };
+            match self.command.mode {
+                // The formatted code is printed to stdout, keep the output to the code
+                OutputMode::Print => {}
+                OutputMode::Write => eprintln!(
+                    "Formatted {files} in {elapsed:.2?}: {changed} changed, {unchanged} unchanged, {failed} failed"
+                ),
+                OutputMode::Check | OutputMode::Diff => eprintln!(
+                    "Checked {files} in {elapsed:.2?}: {changed} unformatted, {unchanged} formatted, {failed} failed"
+                ),
+            }
+        }
+
+        if failed > 0 {
+            ExitCode::from(EXIT_ERROR)
+        } else if matches!(self.command.mode, OutputMode::Check | OutputMode::Diff) && changed > 0 {
+            ExitCode::from(EXIT_UNFORMATTED)
+        } else {
+            ExitCode::SUCCESS
+        }
+    }
+
+    fn format_stdin(&self) -> FileReport {
+        let name = self
+            .command
+            .stdin_filepath
+            .as_deref()
+            .unwrap_or(Path::new("(stdin)"));
+
+        let mut source_text = String::new();
+        if let Err(err) = io::stdin().read_to_string(&mut source_text) {
+            return FileReport::error(name, err);
+        }
+
+        let source_type = match &self.command.stdin_filepath {
+            Some(path) => match SourceType::from_path(path) {
+                Ok(source_type) => source_type,
+                Err(err) => return FileReport::error(name, err),
+            },
+            None => SourceType::mjs(),
+        };
+
+        let options = match self.options(name) {
+            Ok(options) => options,
+            Err(err) => return FileReport::failed(err.to_string()),
+        };
+
+        match self.format(&source_text, source_type, options) {
+            Ok(formatted) => self.report(name, &source_text, formatted),
+            Err(err) => FileReport::error(name, err),
+        }
+    }
+
+    fn format_file(&self, path: &Path) -> FileReport {
+        let source_type = match SourceType::from_path(path) {
+            Ok(source_type) => source_type,
+            Err(err) => return FileReport::error(path, err),
+        };
+        let source_text = match fs::read_to_string(path) {
+            Ok(source_text) => source_text,
+            Err(err) => return FileReport::error(path, err),
+        };
+
+        let options = match self.options(path) {
+            Ok(options) => options,
+            Err(err) => return FileReport::failed(err.to_string()),
+        };
+
+        let formatted = match self.format(&source_text, source_type, options) {
+            Ok(formatted) => formatted,
+            Err(err) => return FileReport::error(path, err),
+        };
+
+        if self.command.mode == OutputMode::Write {
+            if formatted == source_text {
+                return FileReport::new(FileStatus::Unchanged, String::new());
+            }
+            if let Err(err) = fs::write(path, formatted) {
+                return FileReport::error(path, err);
+            }
+            return FileReport::new(FileStatus::Changed, format!("{}\n", path.display()));
+        }
+
+        self.report(path, &source_text, formatted)
+    }
+
+    /// Returns the options for the file at `path`: the options of the command line on top of
+    /// the ones of the configuration files.
+    fn options(&self, path: &Path) -> Result<FormatOptions, ConfigError> {
+        let mut config = if self.command.no_config {
+            FormatConfig::default()
+        } else {
+            self.resolver.resolve(path)?
+        };
+        config.merge(&self.command.config);
+
+        let mut options = config.apply(FormatOptions::default());
+        options.print_ir = self.command.print_ir;
+        Ok(options)
+    }
+
+    fn format(
+        &self,
+        source_text: &str,
+        source_type: SourceType,
+        options: FormatOptions,
+    ) -> FormatResult<String> {
+        ALLOCATOR.with_borrow_mut(|allocator| {
+            let result = format_source_with_allocator(allocator, source_text, source_type, options);
+            allocator.reset();
+            result
+        })
+    }
+
+    /// Reports the formatted code according to the output mode. Code read from stdin can't be
+    /// written back, so it gets printed with `--write`.
+    fn report(&self, path: &Path, source_text: &str, formatted: String) -> FileReport {
+        let changed = formatted != source_text;
+        let output = match self.command.mode {
+            OutputMode::Print | OutputMode::Write => formatted,
+            OutputMode::Check if changed => format!("{}\n", path.display()),
+            OutputMode::Diff if changed => unified_diff(
+                &path.display().to_string(),
+                source_text,
+                &formatted,
+                self.command.diff_context,
+                io::stdout().is_terminal(),
+            ),
+            OutputMode::Check | OutputMode::Diff => String::new(),
+        };
+
+        let status = if changed {
+            FileStatus::Changed
+        } else {
+            FileStatus::Unchanged
+        };
+        FileReport::new(status, output)
+    }
+}
@@ -0,0 +1,260 @@
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use ignore::WalkBuilder;
+use ignore::gitignore::GitignoreBuilder;
+use ignore::overrides::{Override, OverrideBuilder};
+use oxc_span::SourceType;
+
+use crate::cli::FormatCommand;
+
+/// Directories that are never walked into: dependencies and version control data.
+const IGNORED_DIRS: [&str; 6] = ["node_modules", ".git", ".hg", ".svn", ".jj", ".sl"];
+
+/// Ignore files read in every directory. `.gitignore` files are read as well.
+const IGNORE_FILES: [&str; 2] = [".prettierignore", ".oxcformatignore"];
+
+/// Collects the files to format from the paths of the command, skipping ignored files.
+pub struct Walk<'a> {
+    paths: &'a [PathBuf],
+    ignore_paths: &'a [PathBuf],
+    /// Whitelist of the include globs. Applied after the ignore files, so that an include glob
+    /// doesn't bring back an ignored file.
+    includes: Override,
+    /// The exclude globs, as overrides of the walker.
+    excludes: Override,
+}
+
+impl<'a> Walk<'a> {
+    pub fn new(command: &'a FormatCommand) -> Result<Self, String> {
+        let cwd = std::env::current_dir().map_err(|err| err.to_string())?;
+        let build_globs = |globs: &[String], prefix: &str| {
+            let mut builder = OverrideBuilder::new(&cwd);
+            for glob in globs {
+                builder
+                    .add(&format!("{prefix}{glob}"))
+                    .map_err(|err| err.to_string())?;
+            }
+            builder.build().map_err(|err| err.to_string())
+        };
+
+        Ok(Self {
+            paths: &command.paths,
+            ignore_paths: &command.ignore_paths,
+            includes: build_globs(&command.include, "")?,
+            excludes: build_globs(&command.exclude, "!")?,
+        })
+    }
+
+    /// Returns the files to format, sorted by path. Directories are walked for files with a
+    /// supported extension, while explicitly passed files are kept unless they are ignored.
+    pub fn collect(&self) -> Vec<PathBuf> {
+        let mut files = vec![];
+        let mut dirs = vec![];
+
+        for path in self.paths {
+            if path.is_dir() {
+                dirs.push(path);
+            } else if self.is_ignored(path) {
+                eprintln!(
+                    "{}: The file is ignored and won't be formatted",
+                    path.display()
+                );
+            } else {
+                files.push(path.clone());
+            }
+        }
+
+        if let Some((first, rest)) = dirs.split_first() {
+            let mut builder = WalkBuilder::new(first);
+            for dir in rest {
+                builder.add(dir);
+            }
+            builder
+                .hidden(false)
+                .git_global(false)
+                .git_exclude(false)
+                .require_git(false)
+                .overrides(self.excludes.clone())
+                .filter_entry(|entry| !is_ignored_dir(entry.file_name()));
+            for name in IGNORE_FILES {
+                builder.add_custom_ignore_filename(name);
+            }
+            for path in self.ignore_paths {
+                if let Some(err) = builder.add_ignore(path) {
+                    eprintln!("{}: {err}", path.display());
+                }
+            }
+
+            for entry in builder.build() {
+                match entry {
+                    Ok(entry) => {
+                        let is_file = entry
+                            .file_type()
+                            .is_some_and(|file_type| file_type.is_file());
+                        if is_file
+                            && SourceType::from_path(entry.path()).is_ok()
+                            && self.is_included(entry.path())
+                        {
+                            files.push(entry.into_path());
+                        }
+                    }
+                    Err(err) => eprintln!("{err}"),
+                }
+            }
+        }
+
+        files.sort();
+        files.dedup();
+        files
+    }
+
+    /// Returns `true` if the walk would skip `path`, following the same rules as the walker:
+    /// ignored directories, the include and exclude globs, the ignore files in any ancestor
+    /// directory, and the `--ignore-path` files.
+    fn is_ignored(&self, path: &Path) -> bool {
+        if path
+            .components()
+            .any(|component| is_ignored_dir(component.as_os_str()))
+        {
+            return true;
+        }
+
+        if self.excludes.matched(path, false).is_ignore() || !self.is_included(path) {
+            return true;
+        }
+
+        let Ok(path) = std::path::absolute(path) else {
+            return false;
+        };
+
+        // Ignore files given on the command line have the lowest precedence, followed by the
+        // ignore files from the outermost to the innermost directory.
+        let mut ignore_files: Vec<PathBuf> = self
+            .ignore_paths
+            .iter()
+            .filter_map(|ignore_path| std::path::absolute(ignore_path).ok())
+            .collect();
+        let mut ancestors: Vec<&Path> = path.ancestors().skip(1).collect();
+        ancestors.reverse();
+        for dir in ancestors {
+            for name in std::iter::once(".gitignore").chain(IGNORE_FILES) {
+                let ignore_file = dir.join(name);
+                if ignore_file.is_file() {
+                    ignore_files.push(ignore_file);
+                }
+            }
+        }
+
+        let mut ignored = false;
+        for ignore_file in ignore_files {
+            let Some(root) = ignore_file.parent() else {
+                continue;
+            };
+            if !path.starts_with(root) {
+                continue;
+            }
+
+            let mut builder = GitignoreBuilder::new(root);
+            if builder.add(&ignore_file).is_some() {
+                continue;
+            }
+            let Ok(gitignore) = builder.build() else {
+                continue;
+            };
+
+            let matched = gitignore.matched_path_or_any_parents(&path, false);
+            if matched.is_ignore() {
+                ignored = true;
+            } else if matched.is_whitelist() {
+                ignored = false;
+            }
+        }
+        ignored
+    }
+
+    fn is_included(&self, path: &Path) -> bool {
+        self.includes.is_empty() || self.includes.matched(path, false).is_whitelist()
+    }
+}
+
+fn is_ignored_dir(name: &OsStr) -> bool {
+    IGNORED_DIRS.iter().any(|dir| name == *dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use bpaf::Args;
+
+    use super::Walk;
+    use crate::cli::format_command;
+    use crate::cli::temp_dir::TempDir;
+
+    fn collect(paths: &[&Path]) -> Vec<PathBuf> {
+        let args: Vec<_> = paths.iter().map(|path| path.to_str().unwrap()).collect();
+        let command = format_command().run_inner(Args::from(&args[..])).unwrap();
+        Walk::new(&command).unwrap().collect()
+    }
+
+    fn tree() -> TempDir {
+        let dir = TempDir::new();
+        dir.write(".gitignore", "*.gen.js\n!keep.gen.js\n");
+        dir.write("a.js", "");
+        dir.write("b.gen.js", "");
+        dir.write("keep.gen.js", "");
+        dir.write("notes.txt", "");
+        dir.write("node_modules/x.js", "");
+        dir.write("sub/.prettierignore", "c.js\n");
+        dir.write("sub/c.js", "");
+        dir.write("sub/d.ts", "");
+        dir.write("sub/deep/.oxcformatignore", "!c.js\n");
+        dir.write("sub/deep/c.js", "");
+        dir
+    }
+
+    #[test]
+    fn walk_directory() {
+        let dir = tree();
+        let root = dir.path();
+
+        assert_eq!(
+            collect(&[root]),
+            vec![
+                root.join("a.js"),
+                root.join("keep.gen.js"),
+                root.join("sub/d.ts"),
+                root.join("sub/deep/c.js"),
+            ]
+        );
+        assert_eq!(
+            collect(&[&root.join("sub")]),
+            vec![root.join("sub/d.ts"), root.join("sub/deep/c.js")]
+        );
+    }
+
+    #[test]
+    fn explicitly_passed_files() {
+        let dir = tree();
+        let root = dir.path();
+        let files = [
+            root.join("a.js"),
+            root.join("b.gen.js"),
+            root.join("keep.gen.js"),
+            root.join("node_modules/x.js"),
+            root.join("sub/c.js"),
+            root.join("sub/deep/c.js"),
+        ];
+        let paths: Vec<_> = files.iter().map(PathBuf::as_path).collect();
+
+        assert_eq!(
+            collect(&paths),
+            vec![
+                root.join("a.js"),
+                root.join("keep.gen.js"),
+                root.join("sub/deep/c.js"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use std::path::{Path, PathBuf};
+
+use globset::GlobMatcher;
+use rustc_hash::FxHashMap;
+
+use crate::config::{FormatConfig, relative_glob};
+use crate::options::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+
+/// A parsed `.editorconfig` file. Unknown properties and invalid values are ignored, as
+/// required by the EditorConfig specification.
+pub(super) struct EditorConfig {
+    /// Directory of the file, which the section globs are relative to.
+    dir: PathBuf,
+    root: bool,
+    sections: Vec<Section>,
+}
+
+struct Section {
+    /// `None` if the glob of the section is invalid.
+    matcher: Option<GlobMatcher>,
+    properties: Vec<(String, String)>,
+}
+
+impl EditorConfig {
+    pub(super) fn parse(dir: &Path, content: &str) -> Self {
+        let mut root = false;
+        let mut sections: Vec<Section> = vec![];
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+                continue;
+            }
+
+            if let Some(glob) = line
+                .strip_prefix('[')
+                .and_then(|line| line.strip_suffix(']'))
+            {
+                sections.push(Section {
+                    matcher: section_matcher(glob),
+                    properties: vec![],
+                });
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim().to_ascii_lowercase();
+            let value = value.trim().to_ascii_lowercase();
+
+            match sections.last_mut() {
+                Some(section) => section.properties.push((key, value)),
+                None if key == "root" => root = value == "true",
+                None => {}
+            }
+        }
+
+        Self {
+            dir: dir.to_path_buf(),
+            root,
+            sections,
+        }
+    }
+
+    pub(super) fn is_root(&self) -> bool {
+        self.root
+    }
+
+    /// Returns the options set by the sections matching `path`.
+    pub(super) fn resolve(&self, path: &Path) -> FormatConfig {
+        let mut config = FormatConfig::default();
+        let Ok(relative_path) = path.strip_prefix(&self.dir) else {
+            return config;
+        };
+
+        let mut properties = FxHashMap::default();
+        for section in &self.sections {
+            if section
+                .matcher
+                .as_ref()
+                .is_some_and(|matcher| matcher.is_match(relative_path))
+            {
+                for (key, value) in &section.properties {
+                    properties.insert(key.as_str(), value.as_str());
+                }
+            }
+        }
+
+        config.indent_style = match properties.get("indent_style") {
+            Some(&"tab") => Some(IndentStyle::Tab),
+            Some(&"space") => Some(IndentStyle::Space),
+            _ => None,
+        };
+
+        let indent_size = match properties.get("indent_size") {
+            Some(&"tab") | None => properties.get("tab_width"),
+            indent_size => indent_size,
+        };
+        config.indent_width = indent_size
+            .and_then(|size| size.parse::<u8>().ok())
+            .and_then(|size| IndentWidth::try_from(size).ok());
+
+        config.line_width = properties
+            .get("max_line_length")
+            .and_then(|width| width.parse::<u16>().ok())
+            .and_then(|width| LineWidth::try_from(width).ok());
+
+        config.line_ending = match properties.get("end_of_line") {
+            Some(&"lf") => Some(LineEnding::Lf),
+            Some(&"crlf") => Some(LineEnding::Crlf),
+            Some(&"cr") => Some(LineEnding::Cr),
+            _ => None,
+        };
+
+        config
+    }
+}
+
+/// Builds the matcher of a section glob, `None` if the glob is invalid.
+fn section_matcher(glob: &str) -> Option<GlobMatcher> {
+    relative_glob(glob).ok().map(|glob| glob.compile_matcher())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::EditorConfig;
+    use crate::options::{IndentStyle, IndentWidth, LineWidth};
+
+    #[test]
+    fn resolve_sections() {
+        let content = "root = true\n\n[*]\nindent_style = space\nindent_size = 4\n\n[*.{js,ts}]\nmax_line_length = 100\n\n[lib/**]\nindent_style = tab\nindent_size = tab\ntab_width = 8\n";
+        let editorconfig = EditorConfig::parse(Path::new("/project"), content);
+        assert!(editorconfig.is_root());
+
+        let config = editorconfig.resolve(Path::new("/project/src/a.ts"));
+        assert_eq!(config.indent_style, Some(IndentStyle::Space));
+        assert_eq!(config.indent_width, IndentWidth::try_from(4).ok());
+        assert_eq!(config.line_width, LineWidth::try_from(100).ok());
+
+        let config = editorconfig.resolve(Path::new("/project/lib/a.css"));
+        assert_eq!(config.indent_style, Some(IndentStyle::Tab));
+        assert_eq!(config.indent_width, IndentWidth::try_from(8).ok());
+        assert_eq!(config.line_width, None);
+    }
+}
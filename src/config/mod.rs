@@ -0,0 +1,387 @@
+//! Discovery and loading of the configuration files that set the [FormatOptions] of a file.
+//!
+//! For every file, the directories are searched from the file upwards for the nearest
+//! configuration file, which is one of (in order of precedence within a directory):
+//! * `.oxcformatrc.json`
+//! * `.prettierrc` (JSON or YAML), `.prettierrc.json`, `.prettierrc.yaml`, `.prettierrc.yml`
+//!   or `.prettierrc.toml`
+//! * the `prettier` key of a `package.json`
+//!
+//! The options from the `.editorconfig` files up to the one with `root = true` apply as
+//! well, with a lower precedence than the configuration file. The `overrides` of the
+//! configuration file that match the file take precedence over its other options.
+
+mod editorconfig;
+mod prettier;
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use globset::{Glob, GlobBuilder, GlobSet};
+use rustc_hash::FxHashMap;
+
+use crate::config::editorconfig::EditorConfig;
+use crate::options::{
+    AmbiguousWidth, ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing, Expand,
+    FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, QuoteProperties, QuoteStyle,
+    Semicolons, TrailingCommas,
+};
+
+/// Names of the configuration files in a directory, in order of precedence.
+const CONFIG_FILES: [&str; 6] = [
+    ".oxcformatrc.json",
+    ".prettierrc",
+    ".prettierrc.json",
+    ".prettierrc.yaml",
+    ".prettierrc.yml",
+    ".prettierrc.toml",
+];
+
+/// Prettier configuration files that need to be evaluated with JavaScript.
+const UNSUPPORTED_CONFIG_FILES: [&str; 9] = [
+    ".prettierrc.js",
+    ".prettierrc.cjs",
+    ".prettierrc.mjs",
+    ".prettierrc.ts",
+    ".prettierrc.json5",
+    "prettier.config.js",
+    "prettier.config.cjs",
+    "prettier.config.mjs",
+    "prettier.config.ts",
+];
+
+/// Options of [FormatOptions] set by a configuration file or the command line. Unset options
+/// keep the value of the options they are applied to.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct FormatConfig {
+    pub indent_style: Option<IndentStyle>,
+    pub indent_width: Option<IndentWidth>,
+    pub line_ending: Option<LineEnding>,
+    pub line_width: Option<LineWidth>,
+    pub quote_style: Option<QuoteStyle>,
+    pub jsx_quote_style: Option<QuoteStyle>,
+    pub quote_properties: Option<QuoteProperties>,
+    pub trailing_commas: Option<TrailingCommas>,
+    pub semicolons: Option<Semicolons>,
+    pub arrow_parentheses: Option<ArrowParentheses>,
+    pub bracket_spacing: Option<BracketSpacing>,
+    pub bracket_same_line: Option<BracketSameLine>,
+    pub attribute_position: Option<AttributePosition>,
+    pub expand: Option<Expand>,
+    pub ambiguous_width: Option<AmbiguousWidth>,
+    pub require_pragma: Option<bool>,
+    pub insert_pragma: Option<bool>,
+}
+
+impl FormatConfig {
+    /// Overwrites the options of `self` with the ones set in `other`.
+    pub fn merge(&mut self, other: &FormatConfig) {
+        macro_rules! merge {
+            ($($field:ident),*) => {
+                $(if other.$field.is_some() {
+                    self.$field = other.$field;
+                })*
+            };
+        }
+
+        merge!(
+            indent_style,
+            indent_width,
+            line_ending,
+            line_width,
+            quote_style,
+            jsx_quote_style,
+            quote_properties,
+            trailing_commas,
+            semicolons,
+            arrow_parentheses,
+            bracket_spacing,
+            bracket_same_line,
+            attribute_position,
+            expand,
+            ambiguous_width,
+            require_pragma,
+            insert_pragma
+        );
+    }
+
+    /// Returns `options` with the options set in `self`.
+    pub fn apply(&self, mut options: FormatOptions) -> FormatOptions {
+        macro_rules! apply {
+            ($($field:ident),*) => {
+                $(if let Some(value) = self.$field {
+                    options.$field = value;
+                })*
+            };
+        }
+
+        apply!(
+            indent_style,
+            indent_width,
+            line_ending,
+            line_width,
+            quote_style,
+            jsx_quote_style,
+            quote_properties,
+            trailing_commas,
+            semicolons,
+            arrow_parentheses,
+            bracket_spacing,
+            bracket_same_line,
+            attribute_position,
+            expand,
+            ambiguous_width,
+            require_pragma,
+            insert_pragma
+        );
+        options
+    }
+}
+
+/// A loaded configuration file.
+#[derive(Debug)]
+struct ConfigFile {
+    /// Directory of the file, which the globs of the overrides are relative to.
+    dir: PathBuf,
+    options: FormatConfig,
+    overrides: Vec<ConfigOverride>,
+}
+
+/// Options that apply to the files matching some globs, e.g.
+/// `{ "files": "*.test.ts", "excludeFiles": "legacy/**", "options": { "semi": false } }`.
+#[derive(Debug)]
+struct ConfigOverride {
+    files: GlobSet,
+    exclude_files: GlobSet,
+    options: FormatConfig,
+}
+
+impl ConfigFile {
+    /// Returns the options of the file at `path`, with the matching overrides applied in order.
+    fn resolve(&self, path: &Path) -> FormatConfig {
+        let mut options = self.options.clone();
+        let Ok(relative_path) = path.strip_prefix(&self.dir) else {
+            return options;
+        };
+
+        for config_override in &self.overrides {
+            if config_override.files.is_match(relative_path)
+                && !config_override.exclude_files.is_match(relative_path)
+            {
+                options.merge(&config_override.options);
+            }
+        }
+        options
+    }
+}
+
+/// Builds a glob matched against paths relative to the directory of a configuration file.
+/// Globs without a `/` match the file name in any directory.
+fn relative_glob(glob: &str) -> Result<Glob, globset::Error> {
+    let glob = if glob.contains('/') {
+        glob.trim_start_matches("./")
+            .trim_start_matches('/')
+            .to_string()
+    } else {
+        format!("**/{glob}")
+    };
+
+    GlobBuilder::new(&glob).literal_separator(true).build()
+}
+
+/// An invalid or unreadable configuration file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConfigError {
+    pub path: PathBuf,
+    pub message: String,
+}
+
+impl ConfigError {
+    fn new(path: &Path, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+type ConfigResult<T> = Result<T, ConfigError>;
+
+/// Finds and loads the configuration of files. Every directory is only searched once, so one
+/// resolver should be used for all the files of a run. It can be shared between threads.
+#[derive(Default)]
+pub struct ConfigResolver {
+    /// Nearest configuration file of each searched directory.
+    config_files: Mutex<FxHashMap<PathBuf, ConfigResult<Option<Arc<ConfigFile>>>>>,
+    /// Parsed `.editorconfig` of each searched directory.
+    editorconfigs: Mutex<FxHashMap<PathBuf, Option<Arc<EditorConfig>>>>,
+}
+
+impl ConfigResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the options to format the file at `path` with: the default options with the
+    /// ones of the configuration files applied.
+    pub fn resolve_options(&self, path: &Path) -> ConfigResult<FormatOptions> {
+        self.resolve(path)
+            .map(|config| config.apply(FormatOptions::default()))
+    }
+
+    /// Returns the options that the configuration files set for the file at `path`.
+    pub fn resolve(&self, path: &Path) -> ConfigResult<FormatConfig> {
+        let path =
+            std::path::absolute(path).map_err(|err| ConfigError::new(path, err.to_string()))?;
+        let Some(dir) = path.parent() else {
+            return Ok(FormatConfig::default());
+        };
+
+        let mut config = self.resolve_editorconfig(dir, &path);
+        if let Some(config_file) = self.find_config_file(dir)? {
+            config.merge(&config_file.resolve(&path));
+        }
+        Ok(config)
+    }
+
+    /// Returns the configuration file in `dir` or the nearest of its ancestors.
+    fn find_config_file(&self, dir: &Path) -> ConfigResult<Option<Arc<ConfigFile>>> {
+        if let Some(cached) = self.config_files.lock().unwrap().get(dir) {
+            return cached.clone();
+        }
+
+        let config = match load_config_file(dir) {
+            Ok(Some(config)) => Ok(Some(Arc::new(config))),
+            Ok(None) => match dir.parent() {
+                Some(parent) => self.find_config_file(parent),
+                None => Ok(None),
+            },
+            Err(err) => Err(err),
+        };
+
+        self.config_files
+            .lock()
+            .unwrap()
+            .insert(dir.to_path_buf(), config.clone());
+        config
+    }
+
+    /// Returns the options of all the `.editorconfig` files that apply to `path`, starting in
+    /// `dir` and going up to the root one.
+    fn resolve_editorconfig(&self, dir: &Path, path: &Path) -> FormatConfig {
+        let mut editorconfigs = vec![];
+        for dir in dir.ancestors() {
+            if let Some(editorconfig) = self.load_editorconfig(dir) {
+                let is_root = editorconfig.is_root();
+                editorconfigs.push(editorconfig);
+                if is_root {
+                    break;
+                }
+            }
+        }
+
+        let mut config = FormatConfig::default();
+        for editorconfig in editorconfigs.iter().rev() {
+            config.merge(&editorconfig.resolve(path));
+        }
+        config
+    }
+
+    fn load_editorconfig(&self, dir: &Path) -> Option<Arc<EditorConfig>> {
+        if let Some(cached) = self.editorconfigs.lock().unwrap().get(dir) {
+            return cached.clone();
+        }
+
+        let editorconfig = fs::read_to_string(dir.join(".editorconfig"))
+            .ok()
+            .map(|content| Arc::new(EditorConfig::parse(dir, &content)));
+
+        self.editorconfigs
+            .lock()
+            .unwrap()
+            .insert(dir.to_path_buf(), editorconfig.clone());
+        editorconfig
+    }
+}
+
+/// Reads the options of a configuration object like the ones of a configuration file, which
+/// is how [FormatOptions] are deserialized. The `overrides` are checked, but not returned.
+#[cfg(feature = "serde")]
+pub(crate) fn parse_options(value: &serde_json::Value) -> Result<FormatConfig, String> {
+    prettier::parse_config(Path::new(""), Path::new(""), value)
+        .map(|config| config.options)
+        .map_err(|err| err.message)
+}
+
+/// Loads the configuration file in `dir`, if there is one.
+fn load_config_file(dir: &Path) -> ConfigResult<Option<ConfigFile>> {
+    for name in CONFIG_FILES {
+        let path = dir.join(name);
+        if let Some(content) = read_file(&path)? {
+            let value = parse_config_file(&path, name, &content)?;
+            return prettier::parse_config(&path, dir, &value).map(Some);
+        }
+    }
+
+    let package_json = dir.join("package.json");
+    if let Some(content) = read_file(&package_json)? {
+        let value: serde_json::Value = serde_json::from_str(&content)
+            .map_err(|err| ConfigError::new(&package_json, err.to_string()))?;
+        match value.get("prettier") {
+            Some(serde_json::Value::String(_)) => {
+                return Err(ConfigError::new(
+                    &package_json,
+                    "Shared configurations referenced by the `prettier` key are not supported",
+                ));
+            }
+            Some(options) => {
+                return prettier::parse_config(&package_json, dir, options).map(Some);
+            }
+            None => {}
+        }
+    }
+
+    for name in UNSUPPORTED_CONFIG_FILES {
+        let path = dir.join(name);
+        if path.is_file() {
+            return Err(ConfigError::new(
+                &path,
+                "This configuration file format is not supported, use `.prettierrc.json` or `.oxcformatrc.json` instead",
+            ));
+        }
+    }
+
+    Ok(None)
+}
+
+fn read_file(path: &Path) -> ConfigResult<Option<String>> {
+    if !path.is_file() {
+        return Ok(None);
+    }
+    fs::read_to_string(path)
+        .map(Some)
+        .map_err(|err| ConfigError::new(path, err.to_string()))
+}
+
+/// Parses a configuration file into a JSON value, based on its name.
+fn parse_config_file(path: &Path, name: &str, content: &str) -> ConfigResult<serde_json::Value> {
+    let result = if name.ends_with(".json") {
+        serde_json::from_str(content).map_err(|err| err.to_string())
+    } else if name.ends_with(".toml") {
+        toml::from_str(content).map_err(|err| err.to_string())
+    } else {
+        // YAML is a superset of JSON, which covers both formats of `.prettierrc`
+        serde_yaml::from_str(content).map_err(|err| err.to_string())
+    };
+    result.map_err(|message| ConfigError::new(path, message))
+}
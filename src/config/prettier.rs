@@ -0,0 +1,371 @@
+use std::path::Path;
+
+use globset::{GlobSet, GlobSetBuilder};
+use serde_json::Value;
+
+use crate::config::{
+    ConfigError, ConfigFile, ConfigOverride, ConfigResult, FormatConfig, relative_glob,
+};
+use crate::options::{
+    AmbiguousWidth, ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing, Expand,
+    IndentStyle, IndentWidth, LineEnding, LineWidth, ObjectWrap, QuoteProperties, QuoteStyle,
+    Semicolons, TrailingCommas,
+};
+
+/// Prettier options that don't affect JavaScript and TypeScript files, accepted so that
+/// existing Prettier configurations can be used as they are.
+const IGNORED_OPTIONS: [&str; 7] = [
+    "$schema",
+    "embeddedLanguageFormatting",
+    "htmlWhitespaceSensitivity",
+    "parser",
+    "plugins",
+    "proseWrap",
+    "vueIndentScriptAndStyle",
+];
+
+/// Reads a configuration file in `dir`, using the names of the Prettier options.
+pub(super) fn parse_config(path: &Path, dir: &Path, value: &Value) -> ConfigResult<ConfigFile> {
+    let mut config = ConfigFile {
+        dir: dir.to_path_buf(),
+        options: FormatConfig::default(),
+        overrides: vec![],
+    };
+
+    let object = match value {
+        Value::Object(object) => object,
+        // An empty YAML file
+        Value::Null => return Ok(config),
+        _ => {
+            return Err(ConfigError::new(
+                path,
+                "The configuration must be an object",
+            ));
+        }
+    };
+
+    for (key, value) in object {
+        let option = OptionValue {
+            path,
+            key: key.clone(),
+            value,
+        };
+        if key == "overrides" {
+            config.overrides = parse_overrides(&option)?;
+        } else {
+            parse_option(&mut config.options, key, &option)?;
+        }
+    }
+
+    Ok(config)
+}
+
+/// Reads `"overrides": [{ "files": ..., "excludeFiles": ..., "options": { ... } }]`.
+fn parse_overrides(overrides: &OptionValue) -> ConfigResult<Vec<ConfigOverride>> {
+    let Some(array) = overrides.value.as_array() else {
+        return Err(overrides.error("an array"));
+    };
+
+    let mut result = vec![];
+    for (index, value) in array.iter().enumerate() {
+        let item = overrides.child(&format!("[{index}]"), value);
+        let Some(object) = value.as_object() else {
+            return Err(item.error("an object"));
+        };
+
+        let mut files = None;
+        let mut exclude_files = GlobSet::empty();
+        let mut options = FormatConfig::default();
+        for (key, value) in object {
+            let field = item.child(&format!(".{key}"), value);
+            match key.as_str() {
+                "files" => files = Some(field.globs()?),
+                "excludeFiles" => exclude_files = field.globs()?,
+                "options" => {
+                    let Some(object) = value.as_object() else {
+                        return Err(field.error("an object"));
+                    };
+                    for (key, value) in object {
+                        parse_option(&mut options, key, &field.child(&format!(".{key}"), value))?;
+                    }
+                }
+                _ => {
+                    return Err(ConfigError::new(
+                        overrides.path,
+                        format!("Unknown field `{}`", field.key),
+                    ));
+                }
+            }
+        }
+
+        let Some(files) = files else {
+            return Err(ConfigError::new(
+                overrides.path,
+                format!("Missing field `{}.files`", item.key),
+            ));
+        };
+        result.push(ConfigOverride {
+            files,
+            exclude_files,
+            options,
+        });
+    }
+
+    Ok(result)
+}
+
+/// Reads the option `name` into `config`.
+fn parse_option(config: &mut FormatConfig, name: &str, option: &OptionValue) -> ConfigResult<()> {
+    match name {
+        "printWidth" => {
+            let width = option.integer(LineWidth::MIN.into(), LineWidth::MAX.into())?;
+            config.line_width = LineWidth::try_from(width as u16).ok();
+        }
+        "tabWidth" => {
+            let width = option.integer(IndentWidth::MIN.into(), IndentWidth::MAX.into())?;
+            config.indent_width = IndentWidth::try_from(width as u8).ok();
+        }
+        "useTabs" => {
+            config.indent_style = Some(if option.bool()? {
+                IndentStyle::Tab
+            } else {
+                IndentStyle::Space
+            });
+        }
+        "semi" => {
+            config.semicolons = Some(if option.bool()? {
+                Semicolons::Always
+            } else {
+                Semicolons::AsNeeded
+            });
+        }
+        "singleQuote" => {
+            config.quote_style = Some(quote_style(option.bool()?));
+        }
+        "jsxSingleQuote" => {
+            config.jsx_quote_style = Some(quote_style(option.bool()?));
+        }
+        "quoteProps" => {
+            config.quote_properties = Some(match option.string(&["as-needed", "preserve"])? {
+                "preserve" => QuoteProperties::Preserve,
+                _ => QuoteProperties::AsNeeded,
+            });
+        }
+        "trailingComma" => {
+            config.trailing_commas = Some(match option.string(&["all", "es5", "none"])? {
+                "es5" => TrailingCommas::Es5,
+                "none" => TrailingCommas::None,
+                _ => TrailingCommas::All,
+            });
+        }
+        "bracketSpacing" => {
+            config.bracket_spacing = Some(BracketSpacing::from(option.bool()?));
+        }
+        "bracketSameLine" => {
+            config.bracket_same_line = Some(BracketSameLine::from(option.bool()?));
+        }
+        "arrowParens" => {
+            config.arrow_parentheses = Some(match option.string(&["always", "avoid"])? {
+                "avoid" => ArrowParentheses::AsNeeded,
+                _ => ArrowParentheses::Always,
+            });
+        }
+        "endOfLine" => {
+            config.line_ending = Some(match option.string(&["lf", "crlf", "cr", "auto"])? {
+                "crlf" => LineEnding::Crlf,
+                "cr" => LineEnding::Cr,
+                "auto" => LineEnding::Auto,
+                _ => LineEnding::Lf,
+            });
+        }
+        "objectWrap" => {
+            let object_wrap = option.string(&["preserve", "collapse"])?;
+            config.expand = object_wrap.parse::<ObjectWrap>().ok().map(Expand::from);
+        }
+        // Not a Prettier option, `objectWrap` has no equivalent of `always`
+        "expand" => {
+            let expand = option.string(&["auto", "always", "never"])?;
+            config.expand = expand.parse::<Expand>().ok();
+        }
+        // Not a Prettier option
+        "ambiguousWidth" => {
+            let width = option.string(&["narrow", "wide"])?;
+            config.ambiguous_width = width.parse::<AmbiguousWidth>().ok();
+        }
+        "singleAttributePerLine" => {
+            config.attribute_position = Some(if option.bool()? {
+                AttributePosition::Multiline
+            } else {
+                AttributePosition::Auto
+            });
+        }
+        "requirePragma" => {
+            config.require_pragma = Some(option.bool()?);
+        }
+        "insertPragma" => {
+            config.insert_pragma = Some(option.bool()?);
+        }
+        name if IGNORED_OPTIONS.contains(&name) => {}
+        _ => {
+            return Err(ConfigError::new(
+                option.path,
+                format!("Unknown option `{}`", option.key),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn quote_style(single_quote: bool) -> QuoteStyle {
+    if single_quote {
+        QuoteStyle::Single
+    } else {
+        QuoteStyle::Double
+    }
+}
+
+/// The value of an option in a configuration file, with the accessors for the expected types.
+struct OptionValue<'a> {
+    path: &'a Path,
+    /// Location of the value in the file, e.g. `overrides[0].options.semi`.
+    key: String,
+    value: &'a Value,
+}
+
+impl<'a> OptionValue<'a> {
+    fn child(&self, suffix: &str, value: &'a Value) -> Self {
+        Self {
+            path: self.path,
+            key: format!("{}{suffix}", self.key),
+            value,
+        }
+    }
+
+    /// Reads a glob or an array of globs.
+    fn globs(&self) -> ConfigResult<GlobSet> {
+        let globs = match self.value {
+            Value::String(glob) => vec![glob.as_str()],
+            Value::Array(array) => array
+                .iter()
+                .map(Value::as_str)
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| self.error("a glob or an array of globs"))?,
+            _ => return Err(self.error("a glob or an array of globs")),
+        };
+
+        let mut builder = GlobSetBuilder::new();
+        for glob in globs {
+            let glob = relative_glob(glob).map_err(|err| {
+                ConfigError::new(self.path, format!("Invalid glob in `{}`: {err}", self.key))
+            })?;
+            builder.add(glob);
+        }
+        builder
+            .build()
+            .map_err(|err| ConfigError::new(self.path, err.to_string()))
+    }
+
+    fn bool(&self) -> ConfigResult<bool> {
+        self.value
+            .as_bool()
+            .ok_or_else(|| self.error("`true` or `false`"))
+    }
+
+    fn integer(&self, min: u64, max: u64) -> ConfigResult<u64> {
+        self.value
+            .as_u64()
+            .filter(|value| (min..=max).contains(value))
+            .ok_or_else(|| self.error(&format!("an integer between {min} and {max}")))
+    }
+
+    /// Returns the value if it is one of `allowed`.
+    fn string(&self, allowed: &[&str]) -> ConfigResult<&'a str> {
+        self.value
+            .as_str()
+            .filter(|value| allowed.contains(value))
+            .ok_or_else(|| {
+                let allowed = allowed
+                    .iter()
+                    .map(|value| format!("\"{value}\""))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                self.error(&format!("one of {allowed}"))
+            })
+    }
+
+    fn error(&self, expected: &str) -> ConfigError {
+        ConfigError::new(
+            self.path,
+            format!(
+                "Invalid value for `{}`: expected {expected}, found `{}`",
+                self.key, self.value
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use serde_json::json;
+
+    use super::parse_config;
+    use crate::options::{AmbiguousWidth, Expand, LineWidth, QuoteStyle, Semicolons};
+
+    #[test]
+    fn resolve_overrides() {
+        let value = json!({
+            "printWidth": 80,
+            "overrides": [
+                { "files": "legacy/**", "options": { "singleQuote": true, "printWidth": 120 } },
+                { "files": ["*.test.ts"], "excludeFiles": "legacy/**", "options": { "semi": false } }
+            ]
+        });
+        let config = parse_config(
+            Path::new("/project/.prettierrc"),
+            Path::new("/project"),
+            &value,
+        )
+        .unwrap();
+
+        let options = config.resolve(Path::new("/project/src/a.ts"));
+        assert_eq!(options.line_width, LineWidth::try_from(80).ok());
+        assert_eq!(options.quote_style, None);
+        assert_eq!(options.semicolons, None);
+
+        let options = config.resolve(Path::new("/project/legacy/a.test.ts"));
+        assert_eq!(options.line_width, LineWidth::try_from(120).ok());
+        assert_eq!(options.quote_style, Some(QuoteStyle::Single));
+        assert_eq!(options.semicolons, None);
+
+        let options = config.resolve(Path::new("/project/src/a.test.ts"));
+        assert_eq!(options.semicolons, Some(Semicolons::AsNeeded));
+    }
+
+    #[test]
+    fn ambiguous_width() {
+        let path = Path::new("/project/.oxcformatrc.json");
+        let dir = Path::new("/project");
+
+        let config = parse_config(path, dir, &json!({ "ambiguousWidth": "wide" })).unwrap();
+        assert_eq!(config.options.ambiguous_width, Some(AmbiguousWidth::Wide));
+
+        assert!(parse_config(path, dir, &json!({ "ambiguousWidth": 2 })).is_err());
+    }
+
+    #[test]
+    fn expand() {
+        let path = Path::new("/project/.oxcformatrc.json");
+        let dir = Path::new("/project");
+
+        let config = parse_config(path, dir, &json!({ "expand": "always" })).unwrap();
+        assert_eq!(config.options.expand, Some(Expand::Always));
+
+        let config = parse_config(path, dir, &json!({ "objectWrap": "collapse" })).unwrap();
+        assert_eq!(config.options.expand, Some(Expand::Never));
+
+        assert!(parse_config(path, dir, &json!({ "expand": "preserve" })).is_err());
+    }
+}
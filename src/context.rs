@@ -1,17 +1,142 @@
+use oxc_ast::Comment;
+use oxc_span::{SourceType, Span};
+
+use crate::format::comments::is_range_start_comment;
 use crate::options::FormatOptions;
+use crate::pragma::find_docblock;
 
 #[derive(Debug, Clone)]
-pub struct FormatContext {
+pub struct FormatContext<'ast> {
     options: FormatOptions,
-    // TODO: Comments
+    source_text: &'ast str,
+    source_type: SourceType,
+    comments: &'ast [Comment],
 }
 
-impl FormatContext {
-    pub fn new(options: FormatOptions) -> Self {
-        Self { options }
+impl<'ast> FormatContext<'ast> {
+    pub fn new(
+        options: FormatOptions,
+        source_text: &'ast str,
+        source_type: SourceType,
+        comments: &'ast [Comment],
+    ) -> Self {
+        Self {
+            options,
+            source_text,
+            source_type,
+            comments,
+        }
     }
 
     pub fn options(&self) -> &FormatOptions {
         &self.options
     }
+
+    /// Returns the whole source text of the program being formatted.
+    pub fn source_text(&self) -> &'ast str {
+        self.source_text
+    }
+
+    /// Returns the type of the program being formatted, e.g. if it's a TypeScript file with JSX.
+    pub fn source_type(&self) -> SourceType {
+        self.source_type
+    }
+
+    /// Returns the comments that are fully contained in `span`.
+    pub fn comments_in(&self, span: Span) -> &'ast [Comment] {
+        let start = self
+            .comments
+            .partition_point(|comment| comment.span.start < span.start);
+        let end = self
+            .comments
+            .partition_point(|comment| comment.span.end <= span.end);
+
+        &self.comments[start..end.max(start)]
+    }
+
+    /// Returns the docblock at the top of the file, see [find_docblock].
+    pub fn docblock(&self) -> Option<&'ast Comment> {
+        find_docblock(self.source_text, self.comments)
+    }
+
+    /// Returns the comments at the top of the file that are printed before the directives and
+    /// statements: the docblock and the comments up to the first code, or up to a
+    /// `// prettier-ignore-start` comment that starts an ignored region.
+    pub fn header_comments(&self) -> &'ast [Comment] {
+        let mut end = if self.source_text.starts_with("#!") {
+            self.source_text
+                .find('\n')
+                .map_or(self.source_text.len(), |position| position + 1)
+        } else {
+            0
+        };
+        let count = self
+            .comments
+            .iter()
+            .take_while(|comment| {
+                let start = comment.span.start as usize;
+                let is_header = self.source_text[end.min(start)..start]
+                    .chars()
+                    .all(char::is_whitespace)
+                    && !is_range_start_comment(comment, self.source_text);
+                end = comment.span.end as usize;
+                is_header
+            })
+            .count();
+
+        &self.comments[..count]
+    }
+
+    /// Returns the comments directly before `span` that are only separated from it, and from each
+    /// other, by whitespace.
+    ///
+    /// Comments on the same line as the code before them are trailing comments of that code and
+    /// are excluded, together with the comments that follow them on the same line:
+    /// `foo(); // comment`.
+    pub fn leading_comments(&self, span: Span) -> &'ast [Comment] {
+        let end = self
+            .comments
+            .partition_point(|comment| comment.span.end <= span.start);
+
+        let mut start = end;
+        let mut next_start = span.start;
+        while start > 0 {
+            let comment = &self.comments[start - 1];
+            let gap = &self.source_text[comment.span.end as usize..next_start as usize];
+            if !gap.chars().all(char::is_whitespace) {
+                break;
+            }
+
+            if self.is_trailing_code_comment(start - 1) {
+                while start < end && !self.comments[start].preceded_by_newline {
+                    start += 1;
+                }
+                break;
+            }
+
+            next_start = comment.span.start;
+            start -= 1;
+        }
+
+        &self.comments[start..end]
+    }
+
+    /// Returns `true` if the comment at `index` is directly preceded by code on the same line.
+    fn is_trailing_code_comment(&self, index: usize) -> bool {
+        let comment = &self.comments[index];
+        let line_start = self.source_text[..comment.span.start as usize]
+            .rfind(['\n', '\r'])
+            .map_or(0, |position| position + 1);
+
+        // A comment before it on the same line decides if the comments are trailing
+        let previous_end = match index.checked_sub(1).map(|index| &self.comments[index]) {
+            Some(previous) if previous.span.end as usize >= line_start => previous.span.end,
+            _ => line_start as u32,
+        };
+
+        let before = &self.source_text[line_start..comment.span.start as usize];
+        let gap = &self.source_text[previous_end as usize..comment.span.start as usize];
+
+        !before.trim().is_empty() && !gap.trim().is_empty()
+    }
 }
@@ -0,0 +1,232 @@
+use oxc_ast::Comment;
+use oxc_span::Span;
+
+use crate::buffer::Buffer;
+use crate::builders::*;
+use crate::format::Format;
+use crate::format_element::normalize_newlines;
+use crate::formatter::Formatter;
+use crate::pragma::insert_format_pragma;
+use crate::write;
+
+/// Returns `true` if the comment suppresses the formatting of the following node:
+/// `// oxc-formatter-ignore` or `// prettier-ignore`.
+pub fn is_suppression_comment(comment: &Comment, source_text: &str) -> bool {
+    let content = comment.content_span();
+    let content = source_text[content.start as usize..content.end as usize].trim();
+
+    matches!(content, "oxc-formatter-ignore" | "prettier-ignore")
+}
+
+/// Returns `true` if the comment starts a region that is printed as it is written:
+/// `// oxc-formatter-ignore-start` or `// prettier-ignore-start`.
+pub fn is_range_start_comment(comment: &Comment, source_text: &str) -> bool {
+    let content = comment.content_span();
+    let content = source_text[content.start as usize..content.end as usize].trim();
+
+    matches!(
+        content,
+        "oxc-formatter-ignore-start" | "prettier-ignore-start"
+    )
+}
+
+/// Returns `true` if the comment ends a region started by [is_range_start_comment].
+pub fn is_range_end_comment(comment: &Comment, source_text: &str) -> bool {
+    let content = comment.content_span();
+    let content = source_text[content.start as usize..content.end as usize].trim();
+
+    matches!(content, "oxc-formatter-ignore-end" | "prettier-ignore-end")
+}
+
+/// Returns `true` if the node at `span` is preceded by a suppression comment.
+pub fn is_suppressed(span: Span, f: &Formatter) -> bool {
+    let source_text = f.context().source_text();
+
+    f.context()
+        .leading_comments(span)
+        .iter()
+        .any(|comment| is_suppression_comment(comment, source_text))
+}
+
+/// Formats a single comment as it is written in the source text.
+pub struct FormatComment<'a> {
+    comment: &'a Comment,
+}
+
+impl<'a> FormatComment<'a> {
+    pub fn new(comment: &'a Comment) -> Self {
+        Self { comment }
+    }
+}
+
+impl Format for FormatComment<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let source_text = f.context().source_text();
+        let text = &source_text[self.comment.span.start as usize..self.comment.span.end as usize];
+
+        write!(f, [dynamic_text(&normalize_newlines(text, ['\r']))]);
+
+        // A line comment must never be followed by other content on the same line
+        if self.comment.is_line() {
+            write!(f, [expand_parent()]);
+        }
+    }
+}
+
+/// Formats the comments inside of a node that aren't attached to any of its children,
+/// e.g. the comments of an empty block `{ /* dangling */ }`.
+///
+/// Comments are separated by a line break if they were on their own line in the source text,
+/// otherwise by a space.
+pub struct FormatDanglingComments<'ast> {
+    comments: &'ast [Comment],
+}
+
+impl<'ast> FormatDanglingComments<'ast> {
+    pub fn new(comments: &'ast [Comment]) -> Self {
+        Self { comments }
+    }
+
+    /// Creates an instance for all comments that are inside of `span`.
+    pub fn in_span(span: Span, f: &Formatter<'_, 'ast>) -> Self {
+        Self::new(f.context().comments_in(span))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.comments.is_empty()
+    }
+}
+
+impl Format for FormatDanglingComments<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        for (idx, comment) in self.comments.iter().enumerate() {
+            if idx > 0 {
+                if comment.preceded_by_newline {
+                    write!(f, [hard_line_break()]);
+                } else {
+                    write!(f, [space()]);
+                }
+            }
+
+            write!(f, [FormatComment::new(comment)]);
+        }
+    }
+}
+
+/// Formats the comments before a node, each followed by a line break if it was on its own line
+/// in the source text.
+pub struct FormatLeadingComments<'ast> {
+    comments: &'ast [Comment],
+}
+
+impl<'ast> FormatLeadingComments<'ast> {
+    /// Creates an instance for the comments directly before `span`.
+    pub fn before(span: Span, f: &Formatter<'_, 'ast>) -> Self {
+        let mut comments = f.context().leading_comments(span);
+
+        // The docblock and the other comments at the top of the file are printed by the program
+        if let Some(last) = f.context().header_comments().last() {
+            let printed = comments.partition_point(|comment| comment.span.end <= last.span.end);
+            comments = &comments[printed..];
+        }
+
+        Self { comments }
+    }
+}
+
+impl Format for FormatLeadingComments<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let source_text = f.context().source_text();
+
+        for comment in self.comments {
+            write!(f, [FormatComment::new(comment)]);
+
+            let is_followed_by_newline = source_text[comment.span.end as usize..]
+                .chars()
+                .take_while(|c| c.is_whitespace())
+                .any(|c| c == '\n' || c == '\r');
+
+            if is_followed_by_newline {
+                write!(f, [hard_line_break()]);
+            } else {
+                write!(f, [space()]);
+            }
+        }
+    }
+}
+
+/// Formats the docblock and the other comments at the top of the file, followed by a line break,
+/// and inserts the `@format` pragma if [crate::FormatOptions::insert_pragma] is enabled. Like
+/// Prettier, a new docblock is printed before the other comments:
+///
+/// ```js
+/// /** @format */
+///
+/// // License
+/// ```
+pub struct FormatDocblock {
+    /// The directive or statement after the comments, used to preserve a blank line before it.
+    next: Option<Span>,
+    has_format_pragma: bool,
+}
+
+impl FormatDocblock {
+    pub fn new(next: Option<Span>, has_format_pragma: bool) -> Self {
+        Self {
+            next,
+            has_format_pragma,
+        }
+    }
+}
+
+impl Format for FormatDocblock {
+    fn fmt(&self, f: &mut Formatter) {
+        let source_text = f.context().source_text();
+        let mut comments = f.context().header_comments();
+
+        if f.options().insert_pragma() && !self.has_format_pragma {
+            let docblock = f.context().docblock();
+            if docblock.is_some() {
+                comments = &comments[1..];
+            }
+
+            let docblock = docblock.map(|docblock| {
+                &source_text[docblock.span.start as usize..docblock.span.end as usize]
+            });
+            write!(
+                f,
+                [dynamic_text(&normalize_newlines(
+                    &insert_format_pragma(docblock),
+                    ['\r']
+                ))]
+            );
+
+            // A new pragma is always separated from what follows by a blank line
+            if !comments.is_empty() || self.next.is_some() {
+                write!(f, [empty_line()]);
+            }
+        }
+
+        for (idx, comment) in comments.iter().enumerate() {
+            write!(f, [FormatComment::new(comment)]);
+
+            let Some(next) = comments
+                .get(idx + 1)
+                .map(|comment| comment.span)
+                .or(self.next)
+            else {
+                return;
+            };
+
+            if get_lines_before(next, f) > 1 {
+                write!(f, [empty_line()]);
+            } else if source_text[comment.span.end as usize..next.start as usize]
+                .contains(['\n', '\r'])
+            {
+                write!(f, [hard_line_break()]);
+            } else {
+                write!(f, [space()]);
+            }
+        }
+    }
+}
@@ -1,38 +1,637 @@
 use oxc_ast::ast::*;
+use oxc_ast::{Comment, match_declaration, match_module_declaration};
+use oxc_span::{GetSpan, Span};
 
 use crate::buffer::Buffer;
 use crate::builders::*;
-use crate::format::FormatNode;
+use crate::format::comments::{
+    FormatDanglingComments, FormatDocblock, is_range_end_comment, is_range_start_comment,
+};
+use crate::format::jsx::FormatJsxInParens;
+use crate::format::ts::FormatParameters;
+use crate::format::verbatim::{format_suppressed_node, format_verbatim_node};
+use crate::format::{Format, FormatNode};
+use crate::format_args;
+use crate::format_element::normalize_newlines;
 use crate::formatter::Formatter;
+use crate::options::Expand;
+use crate::pragma::{FORMAT_PRAGMAS, has_docblock_pragma};
 use crate::write;
 
 // TODO: Split this into multiple files by node?
 
 impl FormatNode for Program<'_> {
     fn fmt_fields(&self, f: &mut Formatter) {
-        let Program { body, .. } = self;
+        let Program {
+            hashbang,
+            directives,
+            body,
+            ..
+        } = self;
 
-        for (idx, stmt) in body.iter().enumerate() {
-            if idx > 0 {
+        // Spans of what follows the hashbang and the directives, to preserve a blank line before it
+        let first_directive_or_statement = directives
+            .first()
+            .map(GetSpan::span)
+            .or_else(|| body.first().map(GetSpan::span));
+        let first_statement = body.first().map(GetSpan::span);
+        let has_format_pragma = FORMAT_PRAGMAS
+            .iter()
+            .any(|pragma| has_docblock_pragma(self, pragma));
+        let header = f
+            .context()
+            .header_comments()
+            .first()
+            .map(|comment| comment.span);
+
+        if let Some(hashbang) = hashbang {
+            write!(f, [hashbang.format()]);
+
+            // An inserted docblock directly follows the hashbang
+            if f.options().insert_pragma() && !has_format_pragma {
                 write!(f, [hard_line_break()]);
+            } else if let Some(next) = header.or(first_directive_or_statement) {
+                write!(f, [FormatLinesBefore(next)]);
             }
+        }
 
-            match stmt {
-                Statement::VariableDeclaration(decl) => {
-                    write!(f, [text(decl.kind.as_str()), space()]);
-                    decl.fmt(f);
-                }
-                _ => {
-                    write!(f, [text("/* TODO: Statement::Xxx */")]);
+        write!(
+            f,
+            [
+                FormatDocblock::new(first_directive_or_statement, has_format_pragma),
+                FormatDirectives::new(directives, first_statement),
+                FormatProgramBody { body },
+                hard_line_break()
+            ]
+        );
+    }
+}
+
+/// Formats the statements of a program. The statements between a `// prettier-ignore-start` and
+/// a `// prettier-ignore-end` comment are printed as they are written, including the comments.
+/// Without an end comment, everything up to the end of the file is kept.
+struct FormatProgramBody<'a, 'ast> {
+    body: &'a [Statement<'ast>],
+}
+
+impl FormatProgramBody<'_, '_> {
+    /// Returns the spans of the ignored regions. Only comments between top level statements
+    /// start or end a region.
+    fn ignored_ranges(&self, f: &Formatter) -> Vec<Span> {
+        let source_text = f.context().source_text();
+        let comments = f
+            .context()
+            .comments_in(Span::new(0, source_text.len() as u32));
+
+        let is_top_level = |comment: &Comment| {
+            let idx = self
+                .body
+                .partition_point(|stmt| stmt.span().end <= comment.span.start);
+            self.body
+                .get(idx)
+                .is_none_or(|stmt| stmt.span().start >= comment.span.end)
+        };
+
+        let mut ranges = Vec::new();
+        let mut comments = comments.iter().filter(|comment| is_top_level(comment));
+        while let Some(start) =
+            comments.find(|comment| is_range_start_comment(comment, source_text))
+        {
+            let end = comments
+                .find(|comment| is_range_end_comment(comment, source_text))
+                .map(|comment| comment.span.end)
+                .or_else(|| self.body.last().map(|stmt| stmt.span().end))
+                .unwrap_or(start.span.end)
+                .max(start.span.end);
+            ranges.push(Span::new(start.span.start, end));
+        }
+
+        ranges
+    }
+}
+
+impl Format for FormatProgramBody<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let ranges = self.ignored_ranges(f);
+        if ranges.is_empty() {
+            write!(f, [FormatStatementList::new(self.body)]);
+            return;
+        }
+
+        let mut ranges = ranges.into_iter().peekable();
+        let mut ignored_until = 0;
+        let mut join = f.join_nodes_with_hardline();
+
+        for stmt in self.body {
+            let span = stmt.span();
+
+            while let Some(range) = ranges.next_if(|range| range.start <= span.start) {
+                join.entry(
+                    range,
+                    &format_suppressed_node(range).without_leading_comments(),
+                );
+                ignored_until = range.end;
+            }
+
+            if span.end <= ignored_until || matches!(stmt, Statement::EmptyStatement(_)) {
+                continue;
+            }
+
+            join.entry(span, &stmt.format());
+        }
+
+        for range in ranges {
+            join.entry(
+                range,
+                &format_suppressed_node(range).without_leading_comments(),
+            );
+        }
+
+        join.finish();
+    }
+}
+
+/// Formats the directive prologue of a program or function body, one directive per line.
+///
+/// A blank line between the last directive and the first statement is preserved.
+pub struct FormatDirectives<'a, 'ast> {
+    directives: &'a [Directive<'ast>],
+    first_statement: Option<Span>,
+}
+
+impl<'a, 'ast> FormatDirectives<'a, 'ast> {
+    pub fn new(directives: &'a [Directive<'ast>], first_statement: Option<Span>) -> Self {
+        Self {
+            directives,
+            first_statement,
+        }
+    }
+}
+
+impl Format for FormatDirectives<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        if self.directives.is_empty() {
+            return;
+        }
+
+        f.join_nodes_with_hardline()
+            .entries(
+                self.directives
+                    .iter()
+                    .map(|directive| (directive.span, directive.format())),
+            )
+            .finish();
+
+        if let Some(next) = self.first_statement {
+            write!(f, [FormatLinesBefore(next)]);
+        }
+    }
+}
+
+/// Writes a hard line break before the node at `span`, or an empty line if the node is
+/// preceded by at least one blank line in the source text.
+struct FormatLinesBefore(Span);
+
+impl Format for FormatLinesBefore {
+    fn fmt(&self, f: &mut Formatter) {
+        if get_lines_before(self.0, f) > 1 {
+            write!(f, [empty_line()]);
+        } else {
+            write!(f, [hard_line_break()]);
+        }
+    }
+}
+
+impl FormatNode for Hashbang<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let Hashbang { value, .. } = self;
+
+        write!(f, [text("#!"), dynamic_text(value.as_str().trim_end())]);
+    }
+}
+
+impl FormatNode for Directive<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let Directive {
+            expression,
+            directive,
+            ..
+        } = self;
+
+        // A directive is identified by its raw text, so its content is never re-escaped.
+        // The quotes are only changed if the content contains no quote at all.
+        let raw = directive.as_str();
+        if raw.contains(['"', '\'']) {
+            let source_text = f.context().source_text();
+            let quote = &source_text[expression.span.start as usize..][..1];
+            write!(
+                f,
+                [dynamic_text(quote), dynamic_text(raw), dynamic_text(quote)]
+            );
+        } else {
+            let quote = if f.options().quote_style().is_double() {
+                text("\"")
+            } else {
+                text("'")
+            };
+            write!(f, [quote, dynamic_text(raw), quote]);
+        }
+
+        write!(f, [FormatStatementSemicolon]);
+    }
+}
+
+impl FormatNode for Statement<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            Statement::BlockStatement(stmt) => stmt.fmt(f),
+            Statement::BreakStatement(stmt) => stmt.fmt(f),
+            Statement::ContinueStatement(stmt) => stmt.fmt(f),
+            Statement::EmptyStatement(stmt) => stmt.fmt(f),
+            Statement::ExpressionStatement(stmt) => stmt.fmt(f),
+            Statement::LabeledStatement(stmt) => stmt.fmt(f),
+            Statement::ReturnStatement(stmt) => stmt.fmt(f),
+            Statement::SwitchStatement(stmt) => stmt.fmt(f),
+            Statement::TryStatement(stmt) => stmt.fmt(f),
+            match_declaration!(Statement) => self.to_declaration().fmt(f),
+            match_module_declaration!(Statement) => self.to_module_declaration().fmt(f),
+            _ => write!(f, [format_verbatim_node(self.span())]),
+        }
+    }
+}
+
+/// Formats a list of statements, one per line.
+///
+/// A single blank line between two statements is preserved and empty statements are removed.
+pub struct FormatStatementList<'a, 'ast> {
+    statements: &'a [Statement<'ast>],
+}
+
+impl<'a, 'ast> FormatStatementList<'a, 'ast> {
+    pub fn new(statements: &'a [Statement<'ast>]) -> Self {
+        Self { statements }
+    }
+}
+
+impl Format for FormatStatementList<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        f.join_nodes_with_hardline()
+            .entries(
+                self.statements
+                    .iter()
+                    .filter(|stmt| !matches!(stmt, Statement::EmptyStatement(_)))
+                    .map(|stmt| (stmt.span(), stmt.format())),
+            )
+            .finish();
+    }
+}
+
+impl FormatNode for BlockStatement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let BlockStatement { span, body, .. } = self;
+
+        write!(f, [text("{")]);
+
+        if body
+            .iter()
+            .all(|stmt| matches!(stmt, Statement::EmptyStatement(_)))
+        {
+            let comments = FormatDanglingComments::in_span(*span, f);
+            if !comments.is_empty() {
+                write!(f, [block_indent(&comments)]);
+            }
+        } else {
+            write!(f, [block_indent(&FormatStatementList::new(body))]);
+        }
+
+        write!(f, [text("}")]);
+    }
+}
+
+impl FormatNode for EmptyStatement {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text(";")]);
+    }
+}
+
+impl FormatNode for ExpressionStatement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ExpressionStatement { expression, .. } = self;
+
+        // Without semicolons, a statement like `/a/.test(b)` would continue the previous one
+        if f.options().semicolons().is_as_needed() && starts_with_asi_hazard(expression, f) {
+            write!(f, [text(";")]);
+        }
+
+        match expression {
+            // A JSX statement never needs the parentheses that wrap multiline JSX elsewhere
+            Expression::JSXElement(element) => write!(f, [element.format()]),
+            Expression::JSXFragment(fragment) => write!(f, [fragment.format()]),
+            _ => write!(f, [expression.format()]),
+        }
+
+        write!(f, [FormatStatementSemicolon]);
+    }
+}
+
+/// Returns whether the `{ ... }` starting at `start` is expanded because of the [Expand] option.
+/// With [Expand::Auto], it stays expanded if there's a line break between the `{` and its first
+/// entry, which starts at `first_start`.
+pub(super) fn should_expand_object(start: u32, first_start: u32, f: &Formatter) -> bool {
+    match f.options().expand() {
+        Expand::Always => true,
+        Expand::Never => false,
+        Expand::Auto => {
+            let source_text = f.context().source_text();
+            source_text[start as usize..first_start as usize].contains('\n')
+        }
+    }
+}
+
+/// Returns `true` if the left-most token of the expression would continue the previous statement
+/// if the semicolon of that statement is omitted: `[`, `(`, `` ` ``, `+`, `-` or `/`.
+fn starts_with_asi_hazard(expression: &Expression, f: &Formatter) -> bool {
+    match expression {
+        Expression::ArrayExpression(_)
+        | Expression::ParenthesizedExpression(_)
+        | Expression::RegExpLiteral(_)
+        | Expression::TemplateLiteral(_) => true,
+        Expression::UnaryExpression(unary) => matches!(
+            unary.operator,
+            UnaryOperator::UnaryPlus | UnaryOperator::UnaryNegation
+        ),
+        Expression::UpdateExpression(update) => update.prefix,
+        Expression::ArrowFunctionExpression(arrow) => {
+            let is_single_identifier = arrow.params.rest.is_none()
+                && matches!(
+                    arrow.params.items.as_slice(),
+                    [param] if param.pattern.kind.is_binding_identifier()
+                        && param.pattern.type_annotation.is_none()
+                );
+            !arrow.r#async && (f.options().arrow_parentheses().is_always() || !is_single_identifier)
+        }
+        Expression::AssignmentExpression(assignment) => match &assignment.left {
+            AssignmentTarget::ArrayAssignmentTarget(_) => true,
+            left => left
+                .as_simple_assignment_target()
+                .and_then(|target| match target.as_member_expression() {
+                    Some(member) => Some(member.object()),
+                    None => target.get_expression(),
+                })
+                .is_some_and(|expression| starts_with_asi_hazard(expression, f)),
+        },
+        Expression::BinaryExpression(binary) => starts_with_asi_hazard(&binary.left, f),
+        Expression::LogicalExpression(logical) => starts_with_asi_hazard(&logical.left, f),
+        Expression::ConditionalExpression(conditional) => {
+            starts_with_asi_hazard(&conditional.test, f)
+        }
+        Expression::SequenceExpression(sequence) => sequence
+            .expressions
+            .first()
+            .is_some_and(|expression| starts_with_asi_hazard(expression, f)),
+        Expression::CallExpression(call) => starts_with_asi_hazard(&call.callee, f),
+        Expression::TaggedTemplateExpression(tagged) => starts_with_asi_hazard(&tagged.tag, f),
+        Expression::ChainExpression(chain) => match &chain.expression {
+            ChainElement::CallExpression(call) => starts_with_asi_hazard(&call.callee, f),
+            ChainElement::TSNonNullExpression(non_null) => {
+                starts_with_asi_hazard(&non_null.expression, f)
+            }
+            element => element
+                .as_member_expression()
+                .is_some_and(|member| starts_with_asi_hazard(member.object(), f)),
+        },
+        Expression::TSAsExpression(expr) => starts_with_asi_hazard(&expr.expression, f),
+        Expression::TSSatisfiesExpression(expr) => starts_with_asi_hazard(&expr.expression, f),
+        Expression::TSNonNullExpression(expr) => starts_with_asi_hazard(&expr.expression, f),
+        Expression::TSInstantiationExpression(expr) => starts_with_asi_hazard(&expr.expression, f),
+        expression => expression
+            .as_member_expression()
+            .is_some_and(|member| starts_with_asi_hazard(member.object(), f)),
+    }
+}
+
+impl FormatNode for BreakStatement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let BreakStatement { label, .. } = self;
+
+        write!(f, [text("break")]);
+        if let Some(label) = label {
+            write!(f, [space(), label.format()]);
+        }
+        write!(f, [FormatStatementSemicolon]);
+    }
+}
+
+impl FormatNode for ContinueStatement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ContinueStatement { label, .. } = self;
+
+        write!(f, [text("continue")]);
+        if let Some(label) = label {
+            write!(f, [space(), label.format()]);
+        }
+        write!(f, [FormatStatementSemicolon]);
+    }
+}
+
+impl FormatNode for LabeledStatement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let LabeledStatement { label, body, .. } = self;
+
+        write!(f, [label.format(), text(":")]);
+
+        // `label:;` is printed without a space, like Prettier does
+        if !matches!(body, Statement::EmptyStatement(_)) {
+            write!(f, [space()]);
+        }
+
+        body.fmt(f);
+    }
+}
+
+impl FormatNode for SwitchStatement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let SwitchStatement {
+            span,
+            discriminant,
+            cases,
+            ..
+        } = self;
+
+        write!(
+            f,
+            [
+                text("switch"),
+                space(),
+                group(&format_args!(
+                    text("("),
+                    soft_block_indent(&discriminant.format()),
+                    text(")")
+                )),
+                space(),
+                text("{")
+            ]
+        );
+
+        if cases.is_empty() {
+            let comments =
+                FormatDanglingComments::in_span(Span::new(discriminant.span().end, span.end), f);
+            if !comments.is_empty() {
+                write!(f, [block_indent(&comments)]);
+            } else {
+                write!(f, [hard_line_break()]);
+            }
+        } else {
+            let format_cases = format_with(|f| {
+                let mut join = f.join_nodes_with_hardline();
+                for (idx, case) in cases.iter().enumerate() {
+                    // Comments of an empty case can't be inside of its span, they are up to the next case
+                    let end = cases.get(idx + 1).map_or(span.end, |next| next.span.start);
+                    let case = FormatSwitchCase { case, end };
+                    join.entry(case.case.span, &case);
                 }
+                join.finish();
+            });
+
+            write!(f, [block_indent(&format_cases)]);
+        }
+
+        write!(f, [text("}")]);
+    }
+}
+
+/// Formats a [SwitchCase] together with the comments that follow it up to the next case,
+/// because those are not part of the span of the case.
+struct FormatSwitchCase<'a, 'ast> {
+    case: &'a SwitchCase<'ast>,
+    /// The start of the next case, or the end of the switch statement.
+    end: u32,
+}
+
+impl Format for FormatSwitchCase<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let SwitchCase {
+            span,
+            test,
+            consequent,
+        } = self.case;
+
+        match test {
+            Some(test) => write!(f, [text("case"), space(), test.format(), text(":")]),
+            None => write!(f, [text("default:")]),
+        }
+
+        let consequent = consequent
+            .iter()
+            .filter(|stmt| !matches!(stmt, Statement::EmptyStatement(_)))
+            .collect::<Vec<_>>();
+
+        match consequent.as_slice() {
+            [] => {}
+            // `case 1: { ... }` hugs the block
+            [Statement::BlockStatement(block)] => write!(f, [space(), block.format()]),
+            _ => write!(
+                f,
+                [indent(&format_args!(
+                    hard_line_break(),
+                    FormatStatementList::new(&self.case.consequent)
+                ))]
+            ),
+        }
+
+        let comments = f.context().comments_in(Span::new(span.end, self.end));
+        if let Some(first) = comments.first() {
+            // Keep `case 1: // comment` and `foo(); // comment` on the same line, but comments on
+            // their own line stay there, e.g. `// falls through` before the next case
+            if first.preceded_by_newline {
+                write!(f, [hard_line_break()]);
+            } else {
+                write!(f, [space()]);
             }
+            write!(f, [FormatDanglingComments::new(comments)]);
+        }
+    }
+}
+
+impl FormatNode for TryStatement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TryStatement {
+            block,
+            handler,
+            finalizer,
+            ..
+        } = self;
+
+        write!(f, [text("try"), space(), block.format()]);
+
+        if let Some(handler) = handler {
+            write!(f, [space(), handler.format()]);
+        }
+
+        if let Some(finalizer) = finalizer {
+            write!(f, [space(), text("finally"), space(), finalizer.format()]);
+        }
+    }
+}
+
+impl FormatNode for CatchClause<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let CatchClause { param, body, .. } = self;
+
+        write!(f, [text("catch"), space()]);
+
+        // Optional catch binding: `catch { ... }`
+        if let Some(param) = param {
+            write!(f, [text("("), param.pattern.format(), text(")"), space()]);
+        }
+
+        write!(f, [body.format()]);
+    }
+}
+
+impl FormatNode for ReturnStatement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ReturnStatement { argument, .. } = self;
+
+        write!(f, [text("return")]);
+
+        if let Some(argument) = argument {
+            write!(f, [space(), argument.format()]);
+        }
+
+        write!(f, [FormatStatementSemicolon]);
+    }
+}
+
+impl FormatNode for Declaration<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            Declaration::VariableDeclaration(decl) => decl.fmt(f),
+            Declaration::FunctionDeclaration(decl) => decl.fmt(f),
+            Declaration::ClassDeclaration(decl) => decl.fmt(f),
+            Declaration::TSTypeAliasDeclaration(decl) => decl.fmt(f),
+            Declaration::TSInterfaceDeclaration(decl) => decl.fmt(f),
+            Declaration::TSEnumDeclaration(decl) => decl.fmt(f),
+            Declaration::TSModuleDeclaration(decl) => decl.fmt(f),
+            _ => write!(f, [format_verbatim_node(self.span())]),
         }
     }
 }
 
 impl FormatNode for VariableDeclaration<'_> {
     fn fmt_fields(&self, f: &mut Formatter) {
-        let VariableDeclaration { declarations, .. } = self;
+        let VariableDeclaration {
+            kind,
+            declarations,
+            declare,
+            ..
+        } = self;
+
+        if *declare {
+            write!(f, [text("declare"), space()]);
+        }
+
+        write!(f, [text(kind.as_str()), space()]);
 
         for (idx, decl) in declarations.iter().enumerate() {
             if idx > 0 {
@@ -43,36 +642,126 @@ impl FormatNode for VariableDeclaration<'_> {
             decl.fmt(f);
         }
 
-        if f.options().semicolons().is_always() {
-            write!(f, [text(";")]);
-        }
+        write!(f, [FormatStatementSemicolon]);
     }
 }
 
 impl FormatNode for VariableDeclarator<'_> {
     fn fmt_fields(&self, f: &mut Formatter) {
-        let VariableDeclarator { id, init, .. } = self;
+        let VariableDeclarator {
+            id, init, definite, ..
+        } = self;
+
+        match &id.type_annotation {
+            // `let a!: string`
+            Some(type_annotation) if *definite => {
+                write!(f, [id.kind.format(), text("!"), type_annotation.format()]);
+            }
+            _ => id.fmt(f),
+        }
+
+        match init {
+            // The decorators of a class are printed on their own lines, so the class starts on
+            // the next line: `const A =\n\t@dec\n\tclass {};`
+            Some(Expression::ClassExpression(class)) if !class.decorators.is_empty() => {
+                write!(
+                    f,
+                    [
+                        text(" ="),
+                        indent(&format_args!(hard_line_break(), class.format()))
+                    ]
+                );
+            }
+            Some(init) => {
+                write!(f, [text(" = ")]);
+                init.fmt(f);
+            }
+            None => {}
+        }
+    }
+}
+
+impl FormatNode for BindingPattern<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let BindingPattern {
+            kind,
+            type_annotation,
+            optional,
+        } = self;
 
-        if let Some(name) = id.get_identifier_name().as_ref() {
-            write!(f, [dynamic_text(name.as_str())]);
+        write!(f, [kind.format()]);
+
+        if *optional {
+            write!(f, [text("?")]);
         }
 
-        if let Some(init) = init {
-            write!(f, [text(" = ")]);
-            init.fmt(f);
+        write!(
+            f,
+            [type_annotation
+                .as_ref()
+                .map(|annotation| annotation.format())]
+        );
+    }
+}
+
+impl FormatNode for BindingPatternKind<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            BindingPatternKind::BindingIdentifier(ident) => ident.fmt(f),
+            BindingPatternKind::AssignmentPattern(pattern) => pattern.fmt(f),
+            _ => write!(f, [format_verbatim_node(self.span())]),
         }
     }
 }
 
+impl FormatNode for AssignmentPattern<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let AssignmentPattern { left, right, .. } = self;
+
+        write!(f, [left.format(), text(" = "), right.format()]);
+    }
+}
+
 impl FormatNode for Expression<'_> {
     fn fmt(&self, f: &mut Formatter) {
         match self {
+            Expression::Identifier(ident) => ident.fmt(f),
             Expression::NumericLiteral(num) => num.fmt(f),
+            Expression::BigIntLiteral(bigint) => bigint.fmt(f),
+            Expression::RegExpLiteral(regex) => regex.fmt(f),
             Expression::StringLiteral(num) => num.fmt(f),
+            Expression::BooleanLiteral(boolean) => boolean.fmt(f),
+            Expression::NullLiteral(null) => null.fmt(f),
+            Expression::TemplateLiteral(template) => template.fmt(f),
+            Expression::UnaryExpression(unary) => unary.fmt(f),
+            Expression::FunctionExpression(function) => function.fmt(f),
+            Expression::ClassExpression(class) => class.fmt(f),
+            Expression::ObjectExpression(object) => object.fmt(f),
+            Expression::ThisExpression(_) => write!(f, [text("this")]),
+            Expression::Super(_) => write!(f, [text("super")]),
+            Expression::CallExpression(call) => call.fmt(f),
+            Expression::NewExpression(new) => new.fmt(f),
+            Expression::StaticMemberExpression(member) => member.fmt(f),
+            Expression::ComputedMemberExpression(member) => member.fmt(f),
+            Expression::PrivateFieldExpression(member) => member.fmt(f),
+            Expression::ChainExpression(chain) => chain.fmt(f),
+            Expression::TSAsExpression(expr) => expr.fmt(f),
+            Expression::TSSatisfiesExpression(expr) => expr.fmt(f),
+            Expression::TSNonNullExpression(expr) => expr.fmt(f),
+            Expression::TSTypeAssertion(expr) => expr.fmt(f),
+            Expression::TSInstantiationExpression(expr) => expr.fmt(f),
             Expression::ArrayExpression(arr) => arr.fmt(f),
-            _ => {
-                write!(f, [text("/* TODO: Expression::Xxx */")]);
+            Expression::ArrowFunctionExpression(arrow) => arrow.fmt(f),
+            Expression::ParenthesizedExpression(paren) => paren.fmt(f),
+            Expression::BinaryExpression(binary) => binary.fmt(f),
+            Expression::LogicalExpression(logical) => logical.fmt(f),
+            Expression::JSXElement(element) => {
+                write!(f, [FormatJsxInParens::new(&element.format())]);
+            }
+            Expression::JSXFragment(fragment) => {
+                write!(f, [FormatJsxInParens::new(&fragment.format())]);
             }
+            _ => write!(f, [format_verbatim_node(self.span())]),
         }
     }
 }
@@ -88,41 +777,1952 @@ impl FormatNode for ArrayExpression<'_> {
             }
 
             match element {
-                ArrayExpressionElement::NumericLiteral(num) => num.fmt(f),
-                _ => {
-                    write!(f, [text("/* TODO: ArrayExpressionElement::Xxx */")]);
-                }
+                ArrayExpressionElement::SpreadElement(spread) => spread.fmt(f),
+                ArrayExpressionElement::Elision(_) => {}
+                element => element.to_expression().fmt(f),
             }
         }
-        write!(f, [text("]")]);
-    }
-}
-
-impl FormatNode for NumericLiteral<'_> {
-    fn fmt_fields(&self, f: &mut Formatter) {
-        let NumericLiteral { raw, .. } = self;
 
-        let raw = raw.expect("NumericLiteral should have a raw value");
+        // `[a, ,]`: a trailing hole needs its own comma
+        if matches!(elements.last(), Some(ArrayExpressionElement::Elision(_))) {
+            write!(f, [text(",")]);
+        }
 
-        write!(f, [dynamic_text(raw.to_string().as_str())]);
+        write!(f, [text("]")]);
     }
 }
 
-impl FormatNode for StringLiteral<'_> {
+impl FormatNode for ObjectExpression<'_> {
     fn fmt_fields(&self, f: &mut Formatter) {
-        let StringLiteral { value, .. } = self;
+        let ObjectExpression {
+            span, properties, ..
+        } = self;
 
-        let quote = || {
-            if f.options().quote_style().is_double() {
-                text("\"")
+        let Some(first) = properties.first() else {
+            let comments = FormatDanglingComments::in_span(*span, f);
+            if comments.is_empty() {
+                write!(f, [text("{}")]);
             } else {
-                text("'")
+                write!(f, [text("{"), block_indent(&comments), text("}")]);
             }
+            return;
         };
 
+        let should_expand = should_expand_object(span.start, first.span().start, f);
+
+        let content = format_with(|f| {
+            let mut join = f.join_nodes_with_soft_line();
+            for (idx, property) in properties.iter().enumerate() {
+                let property_with_comma = format_with(|f| {
+                    write!(f, [property.format()]);
+                    if idx < properties.len() - 1 {
+                        write!(f, [text(",")]);
+                    }
+                });
+                join.entry(property.span(), &property_with_comma);
+            }
+            join.finish();
+
+            if !f.options().trailing_commas().is_none() {
+                write!(f, [if_group_breaks(&text(","))]);
+            }
+        });
+
         write!(
             f,
-            [quote(), dynamic_text(value.to_string().as_str()), quote()]
+            [group(&format_args!(
+                text("{"),
+                soft_block_indent_with_maybe_space(&content, f.options().bracket_spacing().value()),
+                text("}")
+            ))
+            .should_expand(should_expand)]
         );
     }
 }
+
+impl FormatNode for ObjectPropertyKind<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            ObjectPropertyKind::ObjectProperty(property) => property.fmt(f),
+            ObjectPropertyKind::SpreadProperty(spread) => spread.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for ObjectProperty<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ObjectProperty {
+            kind,
+            key,
+            value,
+            method,
+            shorthand,
+            computed,
+            ..
+        } = self;
+
+        if let Expression::FunctionExpression(function) = value
+            && (*method || !matches!(kind, PropertyKind::Init))
+        {
+            write!(
+                f,
+                [FormatMethod {
+                    kind: *kind,
+                    key,
+                    computed: *computed,
+                    optional: false,
+                    function,
+                }]
+            );
+            return;
+        }
+
+        if *shorthand {
+            // `{ a = 1 }` in an assignment target is a shorthand with a default value
+            write!(f, [value.format()]);
+            return;
+        }
+
+        write!(
+            f,
+            [
+                FormatPropertyKey::new(key, *computed),
+                text(":"),
+                space(),
+                value.format()
+            ]
+        );
+    }
+}
+
+impl FormatNode for NumericLiteral<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let NumericLiteral { raw, .. } = self;
+
+        let raw = raw.expect("NumericLiteral should have a raw value");
+
+        write!(f, [dynamic_text(raw.to_string().as_str())]);
+    }
+}
+
+impl FormatNode for BigIntLiteral<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let BigIntLiteral { raw, .. } = self;
+
+        // `0XABn` -> `0xabn`
+        write!(f, [dynamic_text(&raw.to_ascii_lowercase())]);
+    }
+}
+
+impl FormatNode for RegExpLiteral<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let source_text = f.context().source_text();
+        let raw = &source_text[self.span.start as usize..self.span.end as usize];
+
+        // The pattern is printed as is, only the flags are sorted like Prettier does: `/a/gmi` -> `/a/gim`
+        let flags_start = raw.rfind('/').map_or(raw.len(), |idx| idx + 1);
+        let (pattern, flags) = raw.split_at(flags_start);
+
+        let mut flags = flags.chars().collect::<Vec<_>>();
+        flags.sort_unstable();
+
+        write!(
+            f,
+            [
+                dynamic_text(pattern),
+                dynamic_text(&flags.into_iter().collect::<String>())
+            ]
+        );
+    }
+}
+
+impl FormatNode for StringLiteral<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let StringLiteral { span, raw, .. } = self;
+
+        // The content is printed as it is written, so its escapes are kept. The preferred quotes
+        // are only used if the content doesn't contain them, otherwise they would need escaping.
+        let raw = raw.map_or_else(
+            || &f.context().source_text()[span.start as usize..span.end as usize],
+            |raw| raw.as_str(),
+        );
+        let (quote, content) = (&raw[..1], &raw[1..raw.len() - 1]);
+        let preferred = if f.options().quote_style().is_double() {
+            "\""
+        } else {
+            "'"
+        };
+        let quote = if content.contains(preferred) {
+            quote
+        } else {
+            preferred
+        };
+
+        write!(
+            f,
+            [
+                dynamic_text(quote),
+                dynamic_text(&normalize_newlines(content, ['\r'])),
+                dynamic_text(quote)
+            ]
+        );
+    }
+}
+
+impl FormatNode for CallExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let CallExpression {
+            callee,
+            type_parameters,
+            arguments,
+            optional,
+            ..
+        } = self;
+
+        write!(f, [callee.format()]);
+
+        if *optional {
+            write!(f, [text("?.")]);
+        }
+
+        write!(
+            f,
+            [
+                type_parameters.as_ref().map(|params| params.format()),
+                FormatArguments::new(arguments)
+            ]
+        );
+    }
+}
+
+impl FormatNode for NewExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let NewExpression {
+            callee,
+            arguments,
+            type_parameters,
+            ..
+        } = self;
+
+        write!(
+            f,
+            [
+                text("new"),
+                space(),
+                callee.format(),
+                type_parameters.as_ref().map(|params| params.format()),
+                FormatArguments::new(arguments)
+            ]
+        );
+    }
+}
+
+/// Formats the arguments of a call or `new` expression: `(a, b)`.
+struct FormatArguments<'a, 'ast> {
+    arguments: &'a [Argument<'ast>],
+}
+
+impl<'a, 'ast> FormatArguments<'a, 'ast> {
+    fn new(arguments: &'a [Argument<'ast>]) -> Self {
+        Self { arguments }
+    }
+}
+
+impl Format for FormatArguments<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let arguments = self.arguments;
+
+        if arguments.is_empty() {
+            write!(f, [text("()")]);
+            return;
+        }
+
+        let content = format_with(|f| {
+            let sep = format_with(|f| write!(f, [text(","), soft_line_break_or_space()]));
+            f.join_with(sep)
+                .entries(arguments.iter().map(|argument| argument.format()))
+                .finish();
+
+            if f.options().trailing_commas().is_all() {
+                write!(f, [if_group_breaks(&text(","))]);
+            }
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                text("("),
+                soft_block_indent(&content),
+                text(")")
+            ))]
+        );
+    }
+}
+
+impl FormatNode for Argument<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            Argument::SpreadElement(spread) => spread.fmt(f),
+            argument => argument.to_expression().fmt(f),
+        }
+    }
+}
+
+impl FormatNode for SpreadElement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("..."), self.argument.format()]);
+    }
+}
+
+impl FormatNode for StaticMemberExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let StaticMemberExpression {
+            object,
+            property,
+            optional,
+            ..
+        } = self;
+
+        let operator = if *optional { "?." } else { "." };
+        write!(f, [object.format(), text(operator), property.format()]);
+    }
+}
+
+impl FormatNode for ComputedMemberExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ComputedMemberExpression {
+            object,
+            expression,
+            optional,
+            ..
+        } = self;
+
+        write!(f, [object.format()]);
+
+        if *optional {
+            write!(f, [text("?.")]);
+        }
+
+        write!(f, [text("["), expression.format(), text("]")]);
+    }
+}
+
+impl FormatNode for PrivateFieldExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let PrivateFieldExpression {
+            object,
+            field,
+            optional,
+            ..
+        } = self;
+
+        let operator = if *optional { "?." } else { "." };
+        write!(f, [object.format(), text(operator), field.format()]);
+    }
+}
+
+impl FormatNode for ChainExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        match &self.expression {
+            ChainElement::CallExpression(call) => call.fmt(f),
+            ChainElement::TSNonNullExpression(expr) => expr.fmt(f),
+            element => element.to_member_expression().fmt(f),
+        }
+    }
+}
+
+impl FormatNode for MemberExpression<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            MemberExpression::StaticMemberExpression(member) => member.fmt(f),
+            MemberExpression::ComputedMemberExpression(member) => member.fmt(f),
+            MemberExpression::PrivateFieldExpression(member) => member.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for Class<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [FormatClass::new(self)]);
+    }
+}
+
+/// Formats a class, optionally without its decorators when they were already printed before the
+/// `export` keyword: `@dec export class A {}`.
+struct FormatClass<'a, 'ast> {
+    class: &'a Class<'ast>,
+    decorators: ClassDecorators,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+enum ClassDecorators {
+    /// Every decorator on its own line.
+    OwnLine,
+    /// Decorators after the `export` keyword stay on the line of the class unless they were
+    /// written on their own lines: `export @dec class A {}`.
+    AfterExport,
+    /// The decorators were already printed before the `export` keyword.
+    Omitted,
+}
+
+impl<'a, 'ast> FormatClass<'a, 'ast> {
+    fn new(class: &'a Class<'ast>) -> Self {
+        Self {
+            class,
+            decorators: ClassDecorators::OwnLine,
+        }
+    }
+
+    fn without_decorators(mut self) -> Self {
+        self.decorators = ClassDecorators::Omitted;
+        self
+    }
+
+    fn after_export(mut self) -> Self {
+        self.decorators = ClassDecorators::AfterExport;
+        self
+    }
+}
+
+impl Format for FormatClass<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let Class {
+            decorators,
+            id,
+            type_parameters,
+            super_class,
+            super_type_parameters,
+            implements,
+            body,
+            r#abstract,
+            declare,
+            ..
+        } = self.class;
+
+        match self.decorators {
+            ClassDecorators::OwnLine => write!(f, [FormatDecorators::for_class(decorators)]),
+            ClassDecorators::AfterExport => write!(f, [FormatDecorators::new(decorators)]),
+            ClassDecorators::Omitted => {}
+        }
+
+        if *declare {
+            write!(f, [text("declare"), space()]);
+        }
+
+        if *r#abstract {
+            write!(f, [text("abstract"), space()]);
+        }
+
+        write!(f, [text("class")]);
+
+        if let Some(id) = id {
+            write!(f, [space(), id.format()]);
+        }
+
+        write!(f, [type_parameters.as_ref().map(|params| params.format())]);
+
+        let implements = implements
+            .as_ref()
+            .filter(|implements| !implements.is_empty());
+
+        let extends = super_class.as_ref().map(|super_class| {
+            format_with(move |f| {
+                write!(
+                    f,
+                    [
+                        text("extends"),
+                        space(),
+                        super_class.format(),
+                        super_type_parameters.as_ref().map(|params| params.format())
+                    ]
+                );
+            })
+        });
+
+        match (extends, implements) {
+            (None, None) => write!(f, [space()]),
+            // A single `extends` clause is never broken
+            (Some(extends), None) => write!(f, [space(), extends, space()]),
+            (extends, implements) => {
+                let heritage_group_id = f.state().group_id("heritage");
+
+                let implements = implements.map(|implements| {
+                    format_with(move |f| {
+                        let sep =
+                            format_with(|f| write!(f, [text(","), soft_line_break_or_space()]));
+                        write!(f, [text("implements"), space()]);
+                        f.join_with(sep)
+                            .entries(implements.iter().map(|implements| implements.format()))
+                            .finish();
+                    })
+                });
+
+                let heritage = format_with(|f| {
+                    if let Some(extends) = &extends {
+                        write!(f, [soft_line_break_or_space(), extends]);
+                    }
+                    if let Some(implements) = &implements {
+                        write!(f, [soft_line_break_or_space(), indent(implements)]);
+                    }
+                });
+
+                // When the heritage clauses break, the `{` is printed on its own line
+                write!(
+                    f,
+                    [
+                        group(&indent(&heritage)).with_group_id(Some(heritage_group_id)),
+                        if_group_breaks(&hard_line_break()).with_group_id(Some(heritage_group_id)),
+                        if_group_fits_on_line(&space()).with_group_id(Some(heritage_group_id))
+                    ]
+                );
+            }
+        }
+
+        write!(f, [body.format()]);
+    }
+}
+
+impl FormatNode for TSClassImplements<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(
+            f,
+            [
+                self.expression.format(),
+                self.type_parameters.as_ref().map(|params| params.format())
+            ]
+        );
+    }
+}
+
+impl FormatNode for ClassBody<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ClassBody { span, body } = self;
+
+        write!(f, [text("{")]);
+
+        if body.is_empty() {
+            let comments = FormatDanglingComments::in_span(*span, f);
+            if !comments.is_empty() {
+                write!(f, [block_indent(&comments)]);
+            }
+        } else {
+            let members = format_with(|f| {
+                let mut join = f.join_nodes_with_hardline();
+                for (idx, member) in body.iter().enumerate() {
+                    let semicolon = format_with(|f| {
+                        if f.options().semicolons().is_as_needed()
+                            && needs_semicolon_after_property(member, body.get(idx + 1))
+                        {
+                            write!(f, [text(";")]);
+                        }
+                    });
+                    join.entry(member.span(), &format_args!(member.format(), semicolon));
+                }
+                join.finish();
+            });
+            write!(f, [block_indent(&members)]);
+        }
+
+        write!(f, [text("}")]);
+    }
+}
+
+/// Returns `true` if a class property needs a semicolon even if semicolons are only printed as
+/// needed, because the next member would continue it otherwise. Like Prettier, these are fields
+/// named `static`, `get` or `set` without a value, and fields followed by a member that starts
+/// with `[`, `*`, `in` or `instanceof`.
+fn needs_semicolon_after_property(member: &ClassElement, next: Option<&ClassElement>) -> bool {
+    let (key, computed, has_value_or_type) = match member {
+        ClassElement::PropertyDefinition(property) => (
+            &property.key,
+            property.computed,
+            property.value.is_some() || property.type_annotation.is_some(),
+        ),
+        ClassElement::AccessorProperty(property) => (
+            &property.key,
+            property.computed,
+            property.value.is_some() || property.type_annotation.is_some(),
+        ),
+        _ => return false,
+    };
+
+    if !computed
+        && !has_value_or_type
+        && matches!(key.static_name().as_deref(), Some("static" | "get" | "set"))
+    {
+        return true;
+    }
+
+    let Some(next) = next else {
+        return false;
+    };
+
+    let (next_key, next_computed) = match next {
+        ClassElement::PropertyDefinition(property) => {
+            if property.r#static || property.accessibility.is_some() || property.readonly {
+                return false;
+            }
+            (&property.key, property.computed)
+        }
+        ClassElement::MethodDefinition(method) => {
+            if method.r#static || method.accessibility.is_some() {
+                return false;
+            }
+            (&method.key, method.computed)
+        }
+        ClassElement::TSIndexSignature(signature) => {
+            return !signature.r#static && !signature.readonly;
+        }
+        ClassElement::AccessorProperty(_) | ClassElement::StaticBlock(_) => return false,
+    };
+
+    if !next_computed && matches!(next_key.static_name().as_deref(), Some("in" | "instanceof")) {
+        return true;
+    }
+
+    match next {
+        ClassElement::PropertyDefinition(_) => next_computed,
+        ClassElement::MethodDefinition(method) => {
+            !method.value.r#async
+                && !matches!(
+                    method.kind,
+                    MethodDefinitionKind::Get | MethodDefinitionKind::Set
+                )
+                && (next_computed || method.value.generator)
+        }
+        _ => false,
+    }
+}
+
+impl FormatNode for ClassElement<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            ClassElement::StaticBlock(block) => block.fmt(f),
+            ClassElement::MethodDefinition(method) => method.fmt(f),
+            ClassElement::PropertyDefinition(property) => property.fmt(f),
+            ClassElement::AccessorProperty(property) => property.fmt(f),
+            ClassElement::TSIndexSignature(signature) => {
+                write!(f, [signature.format(), FormatStatementSemicolon]);
+            }
+        }
+    }
+}
+
+/// Formats the decorators of a class or class member. The decorators of a class are always printed
+/// on their own lines. The decorators of a member stay on the same line as the member if they were
+/// written on the same line, otherwise every decorator is printed on its own line.
+struct FormatDecorators<'a, 'ast> {
+    decorators: &'a [Decorator<'ast>],
+    is_class: bool,
+}
+
+impl<'a, 'ast> FormatDecorators<'a, 'ast> {
+    fn new(decorators: &'a [Decorator<'ast>]) -> Self {
+        Self {
+            decorators,
+            is_class: false,
+        }
+    }
+
+    fn for_class(decorators: &'a [Decorator<'ast>]) -> Self {
+        Self {
+            decorators,
+            is_class: true,
+        }
+    }
+}
+
+impl Format for FormatDecorators<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let source_text = f.context().source_text();
+        let has_newline = self.is_class
+            || self.decorators.iter().any(|decorator| {
+                source_text[decorator.span.end as usize..]
+                    .chars()
+                    .take_while(|c| c.is_whitespace())
+                    .any(|c| c == '\n' || c == '\r')
+            });
+
+        for decorator in self.decorators {
+            if has_newline {
+                write!(f, [decorator.format(), hard_line_break()]);
+            } else {
+                write!(f, [decorator.format(), space()]);
+            }
+        }
+    }
+}
+
+/// Formats the decorators of an exported class that were written before the `export` keyword,
+/// each on its own line:
+///
+/// ```ts
+/// @dec
+/// export class A {}
+/// ```
+struct FormatDecoratorsBeforeExport<'a, 'ast> {
+    decorators: &'a [Decorator<'ast>],
+}
+
+impl<'a, 'ast> FormatDecoratorsBeforeExport<'a, 'ast> {
+    fn new(decorators: &'a [Decorator<'ast>]) -> Self {
+        Self { decorators }
+    }
+
+    /// The span of an export declaration starts after decorators that precede the `export` keyword.
+    fn is_before_export(decorators: &[Decorator], export_span: Span) -> bool {
+        decorators
+            .first()
+            .is_some_and(|decorator| decorator.span.start < export_span.start)
+    }
+}
+
+impl Format for FormatDecoratorsBeforeExport<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        for decorator in self.decorators {
+            write!(f, [decorator.format(), hard_line_break()]);
+        }
+    }
+}
+
+impl FormatNode for Decorator<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("@"), self.expression.format()]);
+    }
+}
+
+/// Formats the modifiers of a class member in the order Prettier prints them:
+/// `declare public static abstract override readonly`.
+#[derive(Default)]
+struct FormatMemberModifiers {
+    declare: bool,
+    accessibility: Option<TSAccessibility>,
+    r#static: bool,
+    r#abstract: bool,
+    r#override: bool,
+    readonly: bool,
+}
+
+impl Format for FormatMemberModifiers {
+    fn fmt(&self, f: &mut Formatter) {
+        if self.declare {
+            write!(f, [text("declare"), space()]);
+        }
+        if let Some(accessibility) = self.accessibility {
+            write!(f, [text(accessibility.as_str()), space()]);
+        }
+        if self.r#static {
+            write!(f, [text("static"), space()]);
+        }
+        if self.r#abstract {
+            write!(f, [text("abstract"), space()]);
+        }
+        if self.r#override {
+            write!(f, [text("override"), space()]);
+        }
+        if self.readonly {
+            write!(f, [text("readonly"), space()]);
+        }
+    }
+}
+
+impl FormatNode for MethodDefinition<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let MethodDefinition {
+            r#type,
+            decorators,
+            key,
+            value,
+            kind,
+            computed,
+            r#static,
+            r#override,
+            optional,
+            accessibility,
+            ..
+        } = self;
+
+        write!(
+            f,
+            [
+                FormatDecorators::new(decorators),
+                FormatMemberModifiers {
+                    accessibility: *accessibility,
+                    r#static: *r#static,
+                    r#abstract: *r#type == MethodDefinitionType::TSAbstractMethodDefinition,
+                    r#override: *r#override,
+                    ..Default::default()
+                }
+            ]
+        );
+
+        let kind = match kind {
+            MethodDefinitionKind::Get => PropertyKind::Get,
+            MethodDefinitionKind::Set => PropertyKind::Set,
+            MethodDefinitionKind::Constructor | MethodDefinitionKind::Method => PropertyKind::Init,
+        };
+
+        write!(
+            f,
+            [FormatMethod {
+                kind,
+                key,
+                computed: *computed,
+                optional: *optional,
+                function: value,
+            }]
+        );
+    }
+}
+
+/// Formats a method of a class or object: `async *key<T>(params): ReturnType {}`.
+struct FormatMethod<'a, 'ast> {
+    kind: PropertyKind,
+    key: &'a PropertyKey<'ast>,
+    computed: bool,
+    optional: bool,
+    function: &'a Function<'ast>,
+}
+
+impl Format for FormatMethod<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let Function {
+            generator,
+            r#async,
+            type_parameters,
+            this_param,
+            params,
+            return_type,
+            body,
+            ..
+        } = self.function;
+
+        match self.kind {
+            PropertyKind::Get => write!(f, [text("get"), space()]),
+            PropertyKind::Set => write!(f, [text("set"), space()]),
+            PropertyKind::Init => {}
+        }
+
+        if *r#async {
+            write!(f, [text("async"), space()]);
+        }
+
+        if *generator {
+            write!(f, [text("*")]);
+        }
+
+        write!(f, [FormatPropertyKey::new(self.key, self.computed)]);
+
+        if self.optional {
+            write!(f, [text("?")]);
+        }
+
+        write!(
+            f,
+            [group(&format_args!(
+                type_parameters.as_ref().map(|params| params.format()),
+                FormatParameters::new(this_param.as_deref(), params),
+                return_type.as_ref().map(|annotation| annotation.format())
+            ))]
+        );
+
+        match body {
+            Some(body) => write!(f, [space(), body.format()]),
+            // An abstract method or an overload signature
+            None => write!(f, [FormatStatementSemicolon]),
+        }
+    }
+}
+
+impl FormatNode for PropertyDefinition<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let PropertyDefinition {
+            r#type,
+            decorators,
+            key,
+            value,
+            computed,
+            r#static,
+            declare,
+            r#override,
+            optional,
+            definite,
+            readonly,
+            type_annotation,
+            accessibility,
+            ..
+        } = self;
+
+        write!(
+            f,
+            [
+                FormatDecorators::new(decorators),
+                FormatMemberModifiers {
+                    declare: *declare,
+                    accessibility: *accessibility,
+                    r#static: *r#static,
+                    r#abstract: *r#type == PropertyDefinitionType::TSAbstractPropertyDefinition,
+                    r#override: *r#override,
+                    readonly: *readonly,
+                },
+                FormatPropertyKey::new(key, *computed)
+            ]
+        );
+
+        if *optional {
+            write!(f, [text("?")]);
+        }
+
+        if *definite {
+            write!(f, [text("!")]);
+        }
+
+        write!(
+            f,
+            [type_annotation
+                .as_ref()
+                .map(|annotation| annotation.format())]
+        );
+
+        if let Some(value) = value {
+            write!(f, [space(), text("="), space(), value.format()]);
+        }
+
+        write!(f, [FormatStatementSemicolon]);
+    }
+}
+
+impl FormatNode for AccessorProperty<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let AccessorProperty {
+            r#type,
+            decorators,
+            key,
+            value,
+            computed,
+            r#static,
+            definite,
+            type_annotation,
+            accessibility,
+            ..
+        } = self;
+
+        write!(
+            f,
+            [
+                FormatDecorators::new(decorators),
+                FormatMemberModifiers {
+                    accessibility: *accessibility,
+                    r#static: *r#static,
+                    r#abstract: *r#type == AccessorPropertyType::TSAbstractAccessorProperty,
+                    ..Default::default()
+                },
+                text("accessor"),
+                space(),
+                FormatPropertyKey::new(key, *computed)
+            ]
+        );
+
+        if *definite {
+            write!(f, [text("!")]);
+        }
+
+        write!(
+            f,
+            [type_annotation
+                .as_ref()
+                .map(|annotation| annotation.format())]
+        );
+
+        if let Some(value) = value {
+            write!(f, [space(), text("="), space(), value.format()]);
+        }
+
+        write!(f, [FormatStatementSemicolon]);
+    }
+}
+
+impl FormatNode for StaticBlock<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let StaticBlock { span, body, .. } = self;
+
+        write!(f, [text("static"), space(), text("{")]);
+
+        if body.is_empty() {
+            let comments = FormatDanglingComments::in_span(*span, f);
+            if !comments.is_empty() {
+                write!(f, [block_indent(&comments)]);
+            }
+        } else {
+            write!(f, [block_indent(&FormatStatementList::new(body))]);
+        }
+
+        write!(f, [text("}")]);
+    }
+}
+
+impl FormatNode for BooleanLiteral {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text(self.as_str())]);
+    }
+}
+
+impl FormatNode for NullLiteral {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("null")]);
+    }
+}
+
+impl FormatNode for TemplateLiteral<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TemplateLiteral {
+            quasis,
+            expressions,
+            ..
+        } = self;
+
+        write!(f, [text("`")]);
+
+        for (idx, quasi) in quasis.iter().enumerate() {
+            write!(f, [quasi.format()]);
+
+            if let Some(expression) = expressions.get(idx) {
+                write!(f, [text("${"), expression.format(), text("}")]);
+            }
+        }
+
+        write!(f, [text("`")]);
+    }
+}
+
+impl FormatNode for TemplateElement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        // The content of a template is printed as is, except for the line breaks that are
+        // printed with the line ending of the options
+        write!(
+            f,
+            [dynamic_text(&normalize_newlines(
+                self.value.raw.as_str(),
+                ['\r']
+            ))]
+        );
+    }
+}
+
+impl FormatNode for UnaryExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let UnaryExpression {
+            operator, argument, ..
+        } = self;
+
+        write!(f, [text(operator.as_str())]);
+
+        if operator.is_keyword() {
+            write!(f, [space()]);
+        }
+
+        write!(f, [argument.format()]);
+    }
+}
+
+impl FormatNode for BinaryExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [BinaryLikeExpression::Binary(self)]);
+    }
+}
+
+impl FormatNode for LogicalExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [BinaryLikeExpression::Logical(self)]);
+    }
+}
+
+/// A binary or logical expression. A chain of operators with the same precedence like
+/// `a && b && c` is printed as a flat list of operands that breaks after each operator.
+#[derive(Clone, Copy)]
+enum BinaryLikeExpression<'a, 'ast> {
+    Binary(&'a BinaryExpression<'ast>),
+    Logical(&'a LogicalExpression<'ast>),
+}
+
+impl<'a, 'ast> BinaryLikeExpression<'a, 'ast> {
+    fn from_expression(expression: &'a Expression<'ast>) -> Option<Self> {
+        match expression {
+            Expression::BinaryExpression(binary) => Some(Self::Binary(binary)),
+            Expression::LogicalExpression(logical) => Some(Self::Logical(logical)),
+            _ => None,
+        }
+    }
+
+    fn left(&self) -> &'a Expression<'ast> {
+        match self {
+            Self::Binary(binary) => &binary.left,
+            Self::Logical(logical) => &logical.left,
+        }
+    }
+
+    fn right(&self) -> &'a Expression<'ast> {
+        match self {
+            Self::Binary(binary) => &binary.right,
+            Self::Logical(logical) => &logical.right,
+        }
+    }
+
+    fn operator(&self) -> &'static str {
+        match self {
+            Self::Binary(binary) => binary.operator.as_str(),
+            Self::Logical(logical) => logical.operator.as_str(),
+        }
+    }
+
+    /// Returns `true` if this expression is the left operand of `parent` and can be printed as
+    /// part of the same chain: `a + b + c`, but not `a == b == c`, `a ** b ** c`, `a % b % c` or
+    /// `a << b << c`, like Prettier.
+    fn should_flatten(&self, parent: &Self) -> bool {
+        self.operator() == parent.operator()
+            && !matches!(
+                self.operator(),
+                "**" | "==" | "!=" | "===" | "!==" | "%" | "<<" | ">>" | ">>>"
+            )
+    }
+
+    /// Returns `true` if the right operand of a logical expression starts on the same line
+    /// as the operator: `a && <div />`, `a || { b }`.
+    fn should_inline(&self) -> bool {
+        let Self::Logical(logical) = self else {
+            return false;
+        };
+
+        match &logical.right {
+            Expression::ObjectExpression(object) => !object.properties.is_empty(),
+            Expression::ArrayExpression(array) => !array.elements.is_empty(),
+            Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Format for BinaryLikeExpression<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        // Collect the chain from the outermost to the innermost expression
+        let mut chain = vec![*self];
+        while let Some(left) = BinaryLikeExpression::from_expression(chain[chain.len() - 1].left())
+        {
+            if !left.should_flatten(&chain[chain.len() - 1]) {
+                break;
+            }
+            chain.push(left);
+        }
+
+        let first = chain[chain.len() - 1].left();
+        let rest = format_with(|f| {
+            for expression in chain.iter().rev() {
+                write!(f, [space(), text(expression.operator())]);
+
+                if expression.should_inline() {
+                    write!(f, [space()]);
+                } else {
+                    write!(f, [soft_line_break_or_space()]);
+                }
+
+                write!(f, [expression.right().format()]);
+            }
+        });
+
+        if self.should_inline() {
+            write!(f, [group(&format_args!(first.format(), rest))]);
+        } else {
+            write!(f, [group(&format_args!(first.format(), indent(&rest)))]);
+        }
+    }
+}
+
+impl FormatNode for ParenthesizedExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ParenthesizedExpression { expression, .. } = self;
+
+        // `(<div />)`: JSX is wrapped in parentheses when it breaks over multiple lines anyway
+        if matches!(
+            expression.without_parentheses(),
+            Expression::JSXElement(_) | Expression::JSXFragment(_)
+        ) {
+            write!(f, [expression.without_parentheses().format()]);
+            return;
+        }
+
+        write!(f, [text("("), expression.format(), text(")")]);
+    }
+}
+
+impl FormatNode for ArrowFunctionExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ArrowFunctionExpression {
+            r#async,
+            type_parameters,
+            params,
+            return_type,
+            body,
+            ..
+        } = self;
+
+        if *r#async {
+            write!(f, [text("async"), space()]);
+        }
+
+        if let Some(type_parameters) = type_parameters {
+            match type_parameters.params.as_slice() {
+                // `<T>() => {}` is parsed as a JSX element in `.tsx` files, the comma makes it a type parameter
+                [param] if param.constraint.is_none() && f.context().source_type().is_jsx() => {
+                    write!(f, [text("<"), param.format(), text(",>")]);
+                }
+                _ => write!(f, [type_parameters.format()]),
+            }
+        }
+
+        if f.options().arrow_parentheses().is_as_needed() && can_avoid_parentheses(self) {
+            write!(f, [params.items[0].pattern.format()]);
+        } else {
+            write!(f, [params.format()]);
+        }
+
+        write!(
+            f,
+            [
+                return_type.as_ref().map(|annotation| annotation.format()),
+                space(),
+                text("=>"),
+                space()
+            ]
+        );
+
+        match self.get_expression() {
+            Some(expression) => write!(f, [expression.format()]),
+            None => write!(f, [body.format()]),
+        }
+    }
+}
+
+/// Returns `true` if the parameters of the arrow function can be printed without parentheses: `a => a`.
+fn can_avoid_parentheses(arrow: &ArrowFunctionExpression) -> bool {
+    let ArrowFunctionExpression {
+        type_parameters,
+        params,
+        return_type,
+        ..
+    } = arrow;
+
+    type_parameters.is_none()
+        && return_type.is_none()
+        && params.rest.is_none()
+        && matches!(
+            params.items.as_slice(),
+            [FormalParameter {
+                pattern: BindingPattern {
+                    kind: BindingPatternKind::BindingIdentifier(_),
+                    type_annotation: None,
+                    optional: false,
+                },
+                accessibility: None,
+                readonly: false,
+                r#override: false,
+                decorators,
+                ..
+            }] if decorators.is_empty()
+        )
+}
+
+impl FormatNode for Function<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let Function {
+            id,
+            generator,
+            r#async,
+            declare,
+            type_parameters,
+            this_param,
+            params,
+            return_type,
+            body,
+            ..
+        } = self;
+
+        if *declare {
+            write!(f, [text("declare"), space()]);
+        }
+
+        if *r#async {
+            write!(f, [text("async"), space()]);
+        }
+
+        write!(f, [text("function")]);
+
+        if *generator {
+            write!(f, [text("*")]);
+        }
+
+        // Like Prettier, `function () {}` also gets a space when it has no name
+        write!(f, [space(), id.as_ref().map(|id| id.format())]);
+
+        write!(
+            f,
+            [group(&format_args!(
+                type_parameters.as_ref().map(|params| params.format()),
+                FormatParameters::new(this_param.as_deref(), params),
+                return_type.as_ref().map(|annotation| annotation.format())
+            ))]
+        );
+
+        match body {
+            Some(body) => write!(f, [space(), body.format()]),
+            // An overload or a `declare function`
+            None => write!(f, [FormatStatementSemicolon]),
+        }
+    }
+}
+
+impl FormatNode for FormalParameters<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let FormalParameters {
+            span, items, rest, ..
+        } = self;
+
+        if items.is_empty() && rest.is_none() {
+            let comments = FormatDanglingComments::in_span(*span, f);
+            write!(f, [text("("), comments, text(")")]);
+            return;
+        }
+
+        let parameters = format_with(|f| {
+            let sep = format_with(|f| write!(f, [text(","), soft_line_break_or_space()]));
+            let mut join = f.join_with(sep);
+            join.entries(items.iter().map(|param| param.format()));
+            if let Some(rest) = rest {
+                join.entry(&rest.format());
+            }
+            join.finish();
+
+            // A rest parameter must not be followed by a comma
+            if rest.is_none() && f.options().trailing_commas().is_all() {
+                write!(f, [if_group_breaks(&text(","))]);
+            }
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                text("("),
+                soft_block_indent(&parameters),
+                text(")")
+            ))
+            .should_expand(should_break_parameters(self))]
+        );
+    }
+}
+
+/// Returns `true` if the parameters are always printed one per line, which is the case for
+/// several parameters where any is a parameter property: `constructor(public a, private b)`.
+pub(crate) fn should_break_parameters(parameters: &FormalParameters) -> bool {
+    parameters.parameters_count() > 1
+        && parameters
+            .items
+            .iter()
+            .any(|parameter| parameter.has_modifier())
+}
+
+impl FormatNode for FormalParameter<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let FormalParameter {
+            decorators,
+            pattern,
+            accessibility,
+            readonly,
+            r#override,
+            ..
+        } = self;
+
+        // Parameter decorators are always kept on the same line: `@Inject() service: Service`
+        for decorator in decorators {
+            write!(f, [decorator.format(), space()]);
+        }
+
+        if let Some(accessibility) = accessibility {
+            write!(f, [text(accessibility.as_str()), space()]);
+        }
+
+        if *r#override {
+            write!(f, [text("override"), space()]);
+        }
+
+        if *readonly {
+            write!(f, [text("readonly"), space()]);
+        }
+
+        write!(f, [pattern.format()]);
+    }
+}
+
+impl FormatNode for BindingRestElement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("..."), self.argument.format()]);
+    }
+}
+
+impl FormatNode for FunctionBody<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let FunctionBody {
+            span,
+            directives,
+            statements,
+        } = self;
+
+        write!(f, [text("{")]);
+
+        if self.is_empty() {
+            let comments = FormatDanglingComments::in_span(*span, f);
+            if !comments.is_empty() {
+                write!(f, [block_indent(&comments)]);
+            }
+        } else {
+            let first_statement = statements.first().map(GetSpan::span);
+            write!(
+                f,
+                [block_indent(&format_args!(
+                    FormatDirectives::new(directives, first_statement),
+                    FormatStatementList::new(statements)
+                ))]
+            );
+        }
+
+        write!(f, [text("}")]);
+    }
+}
+
+/// Formats the key of a property, wrapped in brackets if it's computed: `[key]`.
+pub struct FormatPropertyKey<'a, 'ast> {
+    key: &'a PropertyKey<'ast>,
+    computed: bool,
+}
+
+impl<'a, 'ast> FormatPropertyKey<'a, 'ast> {
+    pub fn new(key: &'a PropertyKey<'ast>, computed: bool) -> Self {
+        Self { key, computed }
+    }
+}
+
+impl Format for FormatPropertyKey<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        if self.computed {
+            write!(f, [text("["), self.key.format(), text("]")]);
+        } else {
+            write!(f, [self.key.format()]);
+        }
+    }
+}
+
+impl FormatNode for PropertyKey<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            PropertyKey::StaticIdentifier(ident) => ident.fmt(f),
+            PropertyKey::PrivateIdentifier(ident) => ident.fmt(f),
+            key => key.to_expression().fmt(f),
+        }
+    }
+}
+
+impl FormatNode for PrivateIdentifier<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("#"), dynamic_text(self.name.as_str())]);
+    }
+}
+
+impl FormatNode for IdentifierName<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [dynamic_text(self.name.as_str())]);
+    }
+}
+
+impl FormatNode for IdentifierReference<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [dynamic_text(self.name.as_str())]);
+    }
+}
+
+impl FormatNode for LabelIdentifier<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [dynamic_text(self.name.as_str())]);
+    }
+}
+
+impl FormatNode for BindingIdentifier<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [dynamic_text(self.name.as_str())]);
+    }
+}
+
+// ---
+
+impl FormatNode for ModuleDeclaration<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            ModuleDeclaration::ImportDeclaration(decl) => decl.fmt(f),
+            ModuleDeclaration::ExportAllDeclaration(decl) => decl.fmt(f),
+            ModuleDeclaration::ExportDefaultDeclaration(decl) => decl.fmt(f),
+            ModuleDeclaration::ExportNamedDeclaration(decl) => decl.fmt(f),
+            ModuleDeclaration::TSExportAssignment(decl) => decl.fmt(f),
+            ModuleDeclaration::TSNamespaceExportDeclaration(decl) => decl.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for ImportDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ImportDeclaration {
+            specifiers,
+            source,
+            phase,
+            with_clause,
+            import_kind,
+            ..
+        } = self;
+
+        write!(f, [text("import")]);
+
+        if import_kind.is_type() {
+            write!(f, [space(), text("type")]);
+        }
+
+        match phase {
+            Some(ImportPhase::Source) => write!(f, [space(), text("source")]),
+            Some(ImportPhase::Defer) => write!(f, [space(), text("defer")]),
+            None => {}
+        }
+
+        // `import "side-effect"` has no specifiers at all, `import {} from "x"` has an empty list
+        if let Some(specifiers) = specifiers {
+            // Default and namespace specifiers are printed as is, named ones are grouped in `{}`
+            let mut standalone = vec![];
+            let mut grouped = vec![];
+            for specifier in specifiers {
+                match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        grouped.push(specifier.as_ref());
+                    }
+                    _ => standalone.push(specifier),
+                }
+            }
+
+            write!(f, [space()]);
+
+            for (idx, specifier) in standalone.iter().enumerate() {
+                if idx > 0 {
+                    write!(f, [text(","), space()]);
+                }
+
+                match specifier {
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(default) => {
+                        default.local.fmt(f);
+                    }
+                    ImportDeclarationSpecifier::ImportNamespaceSpecifier(namespace) => {
+                        write!(
+                            f,
+                            [
+                                text("*"),
+                                space(),
+                                text("as"),
+                                space(),
+                                namespace.local.format()
+                            ]
+                        );
+                    }
+                    ImportDeclarationSpecifier::ImportSpecifier(_) => unreachable!(),
+                }
+            }
+
+            if !grouped.is_empty() || standalone.is_empty() {
+                if !standalone.is_empty() {
+                    write!(f, [text(","), space()]);
+                }
+                write!(f, [FormatNamedSpecifiers::new(&grouped, standalone.len())]);
+            }
+
+            write!(f, [space(), text("from")]);
+        }
+
+        write!(f, [space(), source.format()]);
+
+        if let Some(with_clause) = with_clause {
+            write!(f, [space(), with_clause.format()]);
+        }
+
+        write!(f, [FormatStatementSemicolon]);
+    }
+}
+
+impl FormatNode for ImportSpecifier<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ImportSpecifier {
+            imported,
+            local,
+            import_kind,
+            ..
+        } = self;
+
+        if import_kind.is_type() {
+            write!(f, [text("type"), space()]);
+        }
+
+        let is_shorthand = match imported {
+            ModuleExportName::StringLiteral(_) => false,
+            _ => imported.name() == local.name,
+        };
+
+        if is_shorthand {
+            local.fmt(f);
+        } else {
+            write!(
+                f,
+                [
+                    imported.format(),
+                    space(),
+                    text("as"),
+                    space(),
+                    local.format()
+                ]
+            );
+        }
+    }
+}
+
+impl FormatNode for WithClause<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let WithClause {
+            attributes_keyword,
+            with_entries,
+            ..
+        } = self;
+
+        write!(f, [attributes_keyword.format(), space(), text("{")]);
+
+        if !with_entries.is_empty() {
+            let bracket_spacing = f.options().bracket_spacing().value();
+            write!(f, [maybe_space(bracket_spacing)]);
+
+            let sep = format_with(|f| write!(f, [text(","), space()]));
+            f.join_with(sep)
+                .entries(with_entries.iter().map(FormatNode::format))
+                .finish();
+
+            write!(f, [maybe_space(bracket_spacing)]);
+        }
+
+        write!(f, [text("}")]);
+    }
+}
+
+impl FormatNode for ImportAttribute<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ImportAttribute { key, value, .. } = self;
+
+        match key {
+            ImportAttributeKey::Identifier(ident) => ident.fmt(f),
+            ImportAttributeKey::StringLiteral(string) => string.fmt(f),
+        }
+
+        write!(f, [text(":"), space(), value.format()]);
+    }
+}
+
+impl FormatNode for ExportNamedDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ExportNamedDeclaration {
+            declaration,
+            specifiers,
+            source,
+            export_kind,
+            with_clause,
+            ..
+        } = self;
+
+        if let Some(Declaration::ClassDeclaration(class)) = declaration
+            && FormatDecoratorsBeforeExport::is_before_export(&class.decorators, self.span)
+        {
+            write!(
+                f,
+                [
+                    FormatDecoratorsBeforeExport::new(&class.decorators),
+                    text("export"),
+                    space(),
+                    FormatClass::new(class).without_decorators()
+                ]
+            );
+            return;
+        }
+
+        write!(f, [text("export"), space()]);
+
+        if let Some(Declaration::ClassDeclaration(class)) = declaration {
+            write!(f, [FormatClass::new(class).after_export()]);
+            return;
+        }
+
+        if let Some(declaration) = declaration {
+            declaration.fmt(f);
+            return;
+        }
+
+        if export_kind.is_type() {
+            write!(f, [text("type"), space()]);
+        }
+
+        let specifiers = specifiers.iter().collect::<Vec<_>>();
+        write!(f, [FormatNamedSpecifiers::new(&specifiers, 0)]);
+
+        if let Some(source) = source {
+            write!(f, [space(), text("from"), space(), source.format()]);
+        }
+
+        if let Some(with_clause) = with_clause {
+            write!(f, [space(), with_clause.format()]);
+        }
+
+        write!(f, [FormatStatementSemicolon]);
+    }
+}
+
+impl FormatNode for ExportSpecifier<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ExportSpecifier {
+            local,
+            exported,
+            export_kind,
+            ..
+        } = self;
+
+        if export_kind.is_type() {
+            write!(f, [text("type"), space()]);
+        }
+
+        let is_shorthand = local.span() == exported.span()
+            || !matches!(local, ModuleExportName::StringLiteral(_))
+                && !matches!(exported, ModuleExportName::StringLiteral(_))
+                && local.name() == exported.name();
+
+        local.fmt(f);
+        if !is_shorthand {
+            write!(f, [space(), text("as"), space(), exported.format()]);
+        }
+    }
+}
+
+impl FormatNode for ExportDefaultDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ExportDefaultDeclaration {
+            span, declaration, ..
+        } = self;
+
+        if let ExportDefaultDeclarationKind::ClassDeclaration(class) = declaration
+            && FormatDecoratorsBeforeExport::is_before_export(&class.decorators, *span)
+        {
+            write!(
+                f,
+                [
+                    FormatDecoratorsBeforeExport::new(&class.decorators),
+                    text("export"),
+                    space(),
+                    text("default"),
+                    space(),
+                    FormatClass::new(class).without_decorators()
+                ]
+            );
+            return;
+        }
+
+        write!(f, [text("export"), space(), text("default"), space()]);
+
+        match declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(decl) => decl.fmt(f),
+            ExportDefaultDeclarationKind::ClassDeclaration(decl) => {
+                write!(f, [FormatClass::new(decl).after_export()]);
+            }
+            ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => decl.fmt(f),
+            _ => {
+                write!(
+                    f,
+                    [
+                        declaration.to_expression().format(),
+                        FormatStatementSemicolon
+                    ]
+                );
+            }
+        }
+    }
+}
+
+impl FormatNode for ExportAllDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let ExportAllDeclaration {
+            exported,
+            source,
+            with_clause,
+            export_kind,
+            ..
+        } = self;
+
+        write!(f, [text("export"), space()]);
+
+        if export_kind.is_type() {
+            write!(f, [text("type"), space()]);
+        }
+
+        write!(f, [text("*")]);
+
+        if let Some(exported) = exported {
+            write!(f, [space(), text("as"), space(), exported.format()]);
+        }
+
+        write!(f, [space(), text("from"), space(), source.format()]);
+
+        if let Some(with_clause) = with_clause {
+            write!(f, [space(), with_clause.format()]);
+        }
+
+        write!(f, [FormatStatementSemicolon]);
+    }
+}
+
+impl FormatNode for TSExportAssignment<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSExportAssignment { expression, .. } = self;
+
+        write!(
+            f,
+            [
+                text("export"),
+                space(),
+                text("="),
+                space(),
+                expression.format(),
+                FormatStatementSemicolon
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSNamespaceExportDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSNamespaceExportDeclaration { id, .. } = self;
+
+        write!(
+            f,
+            [
+                text("export"),
+                space(),
+                text("as"),
+                space(),
+                text("namespace"),
+                space(),
+                id.format(),
+                FormatStatementSemicolon
+            ]
+        );
+    }
+}
+
+impl FormatNode for ModuleExportName<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            ModuleExportName::IdentifierName(ident) => ident.fmt(f),
+            ModuleExportName::IdentifierReference(ident) => ident.fmt(f),
+            ModuleExportName::StringLiteral(string) => string.fmt(f),
+        }
+    }
+}
+
+/// Formats the `{ a, b as c }` part of import and export declarations.
+///
+/// The list only breaks over multiple lines if it has more than one entry or follows
+/// a default/namespace specifier, like Prettier does. Such lists are also expanded like
+/// objects, depending on the [Expand] option.
+struct FormatNamedSpecifiers<'a, T> {
+    specifiers: &'a [&'a T],
+    standalone_count: usize,
+}
+
+impl<'a, T> FormatNamedSpecifiers<'a, T> {
+    fn new(specifiers: &'a [&'a T], standalone_count: usize) -> Self {
+        Self {
+            specifiers,
+            standalone_count,
+        }
+    }
+}
+
+impl<T: FormatNode> Format for FormatNamedSpecifiers<'_, T> {
+    fn fmt(&self, f: &mut Formatter) {
+        if self.specifiers.is_empty() {
+            write!(f, [text("{}")]);
+            return;
+        }
+
+        let bracket_spacing = f.options().bracket_spacing().value();
+
+        // The `{` is the last one before the first specifier, as only comments can be between them
+        let first_start = self.specifiers[0].span().start;
+        let source_text = f.context().source_text();
+        let start = source_text[..first_start as usize].rfind('{').unwrap_or(0) as u32;
+        let should_expand = should_expand_object(start, first_start, f);
+
+        // A single specifier only breaks if the list is expanded
+        let can_break = self.specifiers.len() > 1 || self.standalone_count > 0 || should_expand;
+
+        let specifiers = format_with(|f| {
+            let sep = format_with(|f| write!(f, [text(","), soft_line_break_or_space()]));
+            f.join_with(sep)
+                .entries(self.specifiers.iter().map(|specifier| specifier.format()))
+                .finish();
+        });
+
+        if can_break {
+            let trailing_comma = format_with(|f| {
+                if !f.options().trailing_commas().is_none() {
+                    write!(f, [if_group_breaks(&text(","))]);
+                }
+            });
+
+            write!(
+                f,
+                [group(&format_args!(
+                    text("{"),
+                    soft_block_indent_with_maybe_space(
+                        &format_args!(specifiers, trailing_comma),
+                        bracket_spacing
+                    ),
+                    text("}")
+                ))
+                .should_expand(should_expand)]
+            );
+        } else {
+            write!(
+                f,
+                [
+                    text("{"),
+                    maybe_space(bracket_spacing),
+                    specifiers,
+                    maybe_space(bracket_spacing),
+                    text("}")
+                ]
+            );
+        }
+    }
+}
+
+/// Prints the semicolon that terminates a statement, unless [crate::options::Semicolons::AsNeeded] is configured.
+pub struct FormatStatementSemicolon;
+
+impl Format for FormatStatementSemicolon {
+    fn fmt(&self, f: &mut Formatter) {
+        if f.options().semicolons().is_always() {
+            write!(f, [text(";")]);
+        }
+    }
+}
@@ -0,0 +1,947 @@
+use oxc_ast::ast::*;
+use oxc_span::{GetSpan, Span};
+
+use crate::buffer::Buffer;
+use crate::builders::*;
+use crate::format::comments::{FormatDanglingComments, is_suppression_comment};
+use crate::format::verbatim::format_suppressed_node;
+use crate::format::{Format, FormatNode};
+use crate::format_element::{FormatElement, FormatElements, normalize_newlines};
+use crate::formatter::Formatter;
+use crate::options::QuoteStyle;
+use crate::write;
+use crate::{best_fitting, format_args};
+
+/// Wraps a JSX element or fragment in parentheses if it breaks over multiple lines:
+///
+/// ```jsx
+/// const a = (
+///   <div>
+///     <span />
+///   </div>
+/// );
+/// ```
+pub struct FormatJsxInParens<'a> {
+    element: &'a dyn Format,
+}
+
+impl<'a> FormatJsxInParens<'a> {
+    pub fn new(element: &'a dyn Format) -> Self {
+        Self { element }
+    }
+}
+
+impl Format for FormatJsxInParens<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        write!(
+            f,
+            [group(&format_args!(
+                if_group_breaks(&text("(")),
+                soft_block_indent(&self.element),
+                if_group_breaks(&text(")"))
+            ))]
+        );
+    }
+}
+
+impl FormatNode for JSXElement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let JSXElement {
+            opening_element,
+            closing_element,
+            children,
+            ..
+        } = self;
+
+        let Some(closing_element) = closing_element else {
+            write!(f, [opening_element.format()]);
+            return;
+        };
+
+        write!(
+            f,
+            [FormatJsxChildren::new(
+                &opening_element.format(),
+                children,
+                &closing_element.format(),
+                opening_element.attributes.len() > 1
+            )]
+        );
+    }
+}
+
+impl FormatNode for JSXFragment<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let JSXFragment { children, .. } = self;
+
+        write!(
+            f,
+            [FormatJsxChildren::new(
+                &text("<>"),
+                children,
+                &text("</>"),
+                false
+            )]
+        );
+    }
+}
+
+impl FormatNode for JSXOpeningElement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let JSXOpeningElement {
+            self_closing,
+            name,
+            attributes,
+            type_parameters,
+            ..
+        } = self;
+
+        let name = format_with(|f| {
+            write!(
+                f,
+                [
+                    name.format(),
+                    type_parameters.as_ref().map(|params| params.format())
+                ]
+            );
+        });
+
+        let end_of_tag = format_with(|f| {
+            if *self_closing {
+                write!(f, [soft_line_break_or_space(), text("/>")]);
+            } else if !f.options().bracket_same_line().value() {
+                write!(f, [soft_line_break(), text(">")]);
+            } else {
+                write!(f, [text(">")]);
+            }
+        });
+
+        // `<div>`, `<br />`
+        if attributes.is_empty() {
+            let end = if *self_closing { " />" } else { ">" };
+            write!(f, [text("<"), name, text(end)]);
+            return;
+        }
+
+        // Don't break up an opening element with a single string attribute, even if it is too long
+        if let [JSXAttributeItem::Attribute(attribute)] = attributes.as_slice()
+            && let Some(JSXAttributeValue::StringLiteral(string)) = &attribute.value
+            && !string.value.contains('\n')
+        {
+            let end = if *self_closing { " />" } else { ">" };
+            write!(
+                f,
+                [group(&format_args!(
+                    text("<"),
+                    name,
+                    space(),
+                    attribute.format(),
+                    text(end)
+                ))]
+            );
+            return;
+        }
+
+        // Multiline string values can only be printed as the content of an expanded tag
+        let should_break = attributes.iter().any(|attribute| {
+            matches!(
+                attribute,
+                JSXAttributeItem::Attribute(attribute)
+                    if matches!(&attribute.value, Some(JSXAttributeValue::StringLiteral(string)) if string.value.contains('\n'))
+            )
+        });
+
+        let attribute_per_line =
+            f.options().attribute_position().is_multiline() && attributes.len() > 1;
+
+        let attributes = format_with(|f| {
+            for attribute in attributes {
+                if attribute_per_line {
+                    write!(f, [hard_line_break()]);
+                } else {
+                    write!(f, [soft_line_break_or_space()]);
+                }
+
+                write!(f, [attribute.format()]);
+            }
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                text("<"),
+                name,
+                indent(&attributes),
+                end_of_tag
+            ))
+            .should_expand(should_break)]
+        );
+    }
+}
+
+impl FormatNode for JSXClosingElement<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("</"), self.name.format(), text(">")]);
+    }
+}
+
+impl FormatNode for JSXElementName<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            JSXElementName::Identifier(ident) => ident.fmt(f),
+            JSXElementName::IdentifierReference(ident) => ident.fmt(f),
+            JSXElementName::NamespacedName(name) => name.fmt(f),
+            JSXElementName::MemberExpression(member) => member.fmt(f),
+            JSXElementName::ThisExpression(_) => write!(f, [text("this")]),
+        }
+    }
+}
+
+impl FormatNode for JSXIdentifier<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [dynamic_text(self.name.as_str())]);
+    }
+}
+
+impl FormatNode for JSXNamespacedName<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let JSXNamespacedName {
+            namespace,
+            property,
+            ..
+        } = self;
+
+        write!(f, [namespace.format(), text(":"), property.format()]);
+    }
+}
+
+impl FormatNode for JSXMemberExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let JSXMemberExpression {
+            object, property, ..
+        } = self;
+
+        write!(f, [object.format(), text("."), property.format()]);
+    }
+}
+
+impl FormatNode for JSXMemberExpressionObject<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            JSXMemberExpressionObject::IdentifierReference(ident) => ident.fmt(f),
+            JSXMemberExpressionObject::MemberExpression(member) => member.fmt(f),
+            JSXMemberExpressionObject::ThisExpression(_) => write!(f, [text("this")]),
+        }
+    }
+}
+
+impl FormatNode for JSXAttributeItem<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            JSXAttributeItem::Attribute(attribute) => attribute.fmt(f),
+            JSXAttributeItem::SpreadAttribute(spread) => spread.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for JSXAttribute<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let JSXAttribute { name, value, .. } = self;
+
+        write!(f, [name.format()]);
+
+        if let Some(value) = value {
+            write!(f, [text("="), value.format()]);
+        }
+    }
+}
+
+impl FormatNode for JSXAttributeName<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            JSXAttributeName::Identifier(ident) => ident.fmt(f),
+            JSXAttributeName::NamespacedName(name) => name.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for JSXAttributeValue<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            JSXAttributeValue::StringLiteral(string) => {
+                write!(f, [FormatJsxString::new(string.span)]);
+            }
+            JSXAttributeValue::ExpressionContainer(container) => {
+                write!(f, [FormatJsxExpressionContainer::new(container, false)]);
+            }
+            JSXAttributeValue::Element(element) => element.fmt(f),
+            JSXAttributeValue::Fragment(fragment) => fragment.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for JSXSpreadAttribute<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("{..."), self.argument.format(), text("}")]);
+    }
+}
+
+/// Formats the string value of a JSX attribute with the quotes of [crate::options::FormatOptions::jsx_quote_style].
+///
+/// JSX strings can't contain escape sequences, quotes are written as HTML entities instead.
+/// Like Prettier, the other quote is used if that results in fewer entities: `title='"'`.
+struct FormatJsxString {
+    span: Span,
+}
+
+impl FormatJsxString {
+    fn new(span: Span) -> Self {
+        Self { span }
+    }
+}
+
+impl Format for FormatJsxString {
+    fn fmt(&self, f: &mut Formatter) {
+        let source_text = f.context().source_text();
+        let raw = &source_text[self.span.start as usize + 1..self.span.end as usize - 1];
+
+        let content = raw.replace("&apos;", "'").replace("&quot;", "\"");
+
+        let preferred = f.options().jsx_quote_style();
+        let preferred_count = content.matches(preferred.as_char()).count();
+        let other_count = content.matches(preferred.other().as_char()).count();
+
+        let quote = if preferred_count > other_count {
+            preferred.other()
+        } else {
+            preferred
+        };
+
+        let content = content.replace(quote.as_char(), quote.as_html_entity());
+        let quote = match quote {
+            QuoteStyle::Double => text("\""),
+            QuoteStyle::Single => text("'"),
+        };
+
+        // Line breaks are part of the value and printed as is
+        let content = normalize_newlines(&content, ['\r']);
+
+        write!(f, [quote, dynamic_text(&content), quote]);
+    }
+}
+
+/// Formats an expression container `{expression}` of a JSX attribute or child.
+struct FormatJsxExpressionContainer<'a, 'ast> {
+    container: &'a JSXExpressionContainer<'ast>,
+    is_child: bool,
+}
+
+impl<'a, 'ast> FormatJsxExpressionContainer<'a, 'ast> {
+    fn new(container: &'a JSXExpressionContainer<'ast>, is_child: bool) -> Self {
+        Self {
+            container,
+            is_child,
+        }
+    }
+}
+
+impl Format for FormatJsxExpressionContainer<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let JSXExpressionContainer { span, expression } = self.container;
+
+        let expression = match expression {
+            // `{/* comment */}`
+            JSXExpression::EmptyExpression(_) => {
+                let comments = f.context().comments_in(*span);
+                let ends_with_line_comment =
+                    comments.last().is_some_and(|comment| comment.is_line());
+
+                write!(
+                    f,
+                    [
+                        text("{"),
+                        FormatDanglingComments::new(comments),
+                        ends_with_line_comment.then_some(hard_line_break()),
+                        text("}")
+                    ]
+                );
+                return;
+            }
+            expression => expression.to_expression(),
+        };
+
+        // Expressions that start and end with a bracket of their own hug the braces
+        let should_hug = match expression {
+            Expression::ArrayExpression(_)
+            | Expression::ObjectExpression(_)
+            | Expression::ArrowFunctionExpression(_)
+            | Expression::CallExpression(_)
+            | Expression::FunctionExpression(_)
+            | Expression::TemplateLiteral(_)
+            | Expression::TaggedTemplateExpression(_)
+            | Expression::JSXElement(_)
+            | Expression::JSXFragment(_) => true,
+            Expression::ConditionalExpression(_)
+            | Expression::BinaryExpression(_)
+            | Expression::LogicalExpression(_) => self.is_child,
+            _ => false,
+        };
+
+        let expression = format_with(|f| match expression {
+            Expression::JSXElement(element) => write!(f, [element.format()]),
+            Expression::JSXFragment(fragment) => write!(f, [fragment.format()]),
+            expression => write!(f, [expression.format()]),
+        });
+
+        if should_hug {
+            write!(
+                f,
+                [group(&format_args!(
+                    text("{"),
+                    expression,
+                    line_suffix_boundary(),
+                    text("}")
+                ))]
+            );
+        } else {
+            write!(
+                f,
+                [group(&format_args!(
+                    text("{"),
+                    soft_block_indent(&expression),
+                    line_suffix_boundary(),
+                    text("}")
+                ))]
+            );
+        }
+    }
+}
+
+/// A child of a JSX element after `{" "}` has been turned into text.
+#[derive(Clone, Copy)]
+enum JsxChildItem<'a, 'ast> {
+    Text(&'a str),
+    Node(&'a JSXChild<'ast>),
+}
+
+impl JsxChildItem<'_, '_> {
+    /// Text that isn't only whitespace with a line break, which JSX removes.
+    fn is_meaningful_text(&self) -> bool {
+        match self {
+            JsxChildItem::Text(text) => {
+                text.chars().any(|c| !is_jsx_whitespace(c)) || !text.contains('\n')
+            }
+            JsxChildItem::Node(_) => false,
+        }
+    }
+
+    fn is_self_closing_element(&self) -> bool {
+        matches!(self, JsxChildItem::Node(JSXChild::Element(element)) if element.closing_element.is_none())
+    }
+}
+
+/// The children of a JSX element printed as an alternating list of content and separators,
+/// like Prettier's `printJsxChildren`.
+#[derive(Clone)]
+enum JsxChildPart<'a> {
+    /// Empty content that keeps content and separators alternating.
+    Empty,
+    Word(&'a str),
+    Node(FormatElement),
+    SoftLine,
+    HardLine,
+    /// A hard line break that preserves a blank line between two children.
+    EmptyLine,
+    /// The line between two words.
+    Line,
+    /// A space that is printed as `{" "}` followed by a line break if the separator breaks.
+    JsxWhitespace,
+    /// `{" "}`
+    RawJsxWhitespace,
+    /// `{" "}` followed by a line break, for leading whitespace.
+    LeadingJsxWhitespace,
+}
+
+impl JsxChildPart<'_> {
+    fn is_empty(&self) -> bool {
+        matches!(self, JsxChildPart::Empty)
+    }
+
+    fn is_line(&self) -> bool {
+        matches!(
+            self,
+            JsxChildPart::SoftLine | JsxChildPart::HardLine | JsxChildPart::EmptyLine
+        )
+    }
+
+    fn is_hard_line(&self) -> bool {
+        matches!(self, JsxChildPart::HardLine | JsxChildPart::EmptyLine)
+    }
+
+    fn is_soft_line(&self) -> bool {
+        matches!(self, JsxChildPart::SoftLine)
+    }
+
+    fn is_jsx_whitespace(&self) -> bool {
+        matches!(self, JsxChildPart::JsxWhitespace)
+    }
+
+    fn will_break(&self) -> bool {
+        match self {
+            JsxChildPart::Node(element) => element.will_break(),
+            JsxChildPart::HardLine
+            | JsxChildPart::EmptyLine
+            | JsxChildPart::LeadingJsxWhitespace => true,
+            _ => false,
+        }
+    }
+}
+
+impl Format for JsxChildPart<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let raw_jsx_whitespace = format_with(|f| {
+            if f.options().quote_style().is_double() {
+                write!(f, [text("{\" \"}")]);
+            } else {
+                write!(f, [text("{' '}")]);
+            }
+        });
+
+        match self {
+            JsxChildPart::Empty => {}
+            JsxChildPart::Word(word) => write!(f, [dynamic_text(word)]),
+            JsxChildPart::Node(element) => write!(f, [element]),
+            JsxChildPart::SoftLine => write!(f, [soft_line_break()]),
+            JsxChildPart::HardLine => write!(f, [hard_line_break()]),
+            JsxChildPart::EmptyLine => write!(f, [empty_line()]),
+            JsxChildPart::Line => write!(f, [soft_line_break_or_space()]),
+            JsxChildPart::JsxWhitespace => write!(
+                f,
+                [
+                    if_group_breaks(&format_args!(raw_jsx_whitespace, soft_line_break())),
+                    if_group_fits_on_line(&space())
+                ]
+            ),
+            JsxChildPart::RawJsxWhitespace => write!(f, [raw_jsx_whitespace]),
+            JsxChildPart::LeadingJsxWhitespace => {
+                write!(f, [raw_jsx_whitespace, hard_line_break()]);
+            }
+        }
+    }
+}
+
+/// Formats a JSX element or fragment with its children.
+///
+/// The children are either printed on the same line as the tags, or on their own lines using
+/// [Formatter::fill] for text. Whitespace is significant in JSX text unless it contains a line
+/// break, so a space is printed as `{" "}` where breaking the line would otherwise remove it.
+///
+/// Like Prettier, the children are always printed on their own lines if the element has JSX
+/// children, multiple expression children or multiple attributes.
+struct FormatJsxChildren<'a, 'ast> {
+    opening: &'a dyn Format,
+    children: &'a [JSXChild<'ast>],
+    closing: &'a dyn Format,
+    has_multiple_attributes: bool,
+}
+
+impl<'a, 'ast> FormatJsxChildren<'a, 'ast> {
+    fn new(
+        opening: &'a dyn Format,
+        children: &'a [JSXChild<'ast>],
+        closing: &'a dyn Format,
+        has_multiple_attributes: bool,
+    ) -> Self {
+        Self {
+            opening,
+            children,
+            closing,
+            has_multiple_attributes,
+        }
+    }
+
+    /// Splits text into words and turns the children into alternating content and separators.
+    fn parts(&self, items: &[JsxChildItem<'a, 'ast>], f: &mut Formatter) -> Vec<JsxChildPart<'a>> {
+        let mut parts = Vec::new();
+        let mut is_next_suppressed = false;
+
+        for (idx, item) in items.iter().enumerate() {
+            let next = items.get(idx + 1);
+
+            let child = match item {
+                JsxChildItem::Text(text) => {
+                    if item.is_meaningful_text() {
+                        is_next_suppressed = false;
+                        push_text_parts(text, next, &mut parts);
+                    } else if text.matches('\n').count() > 1 {
+                        // Keep (up to one) blank line between tags and expressions
+                        parts.extend([JsxChildPart::Empty, JsxChildPart::EmptyLine]);
+                    }
+                    continue;
+                }
+                JsxChildItem::Node(child) => child,
+            };
+
+            // `{/* prettier-ignore */}` suppresses the formatting of the next child
+            let element = if std::mem::take(&mut is_next_suppressed) {
+                f.intern(&format_suppressed_node(child.span()).without_leading_comments())
+            } else {
+                f.intern(&child.format())
+            };
+            parts.push(element.map_or(JsxChildPart::Empty, JsxChildPart::Node));
+
+            if let JSXChild::ExpressionContainer(container) = child
+                && matches!(container.expression, JSXExpression::EmptyExpression(_))
+            {
+                let source_text = f.context().source_text();
+                is_next_suppressed = f
+                    .context()
+                    .comments_in(container.span)
+                    .iter()
+                    .any(|comment| is_suppression_comment(comment, source_text));
+            }
+
+            match next {
+                Some(next @ JsxChildItem::Text(text)) if next.is_meaningful_text() => {
+                    let first_word = text
+                        .trim_matches(is_jsx_whitespace)
+                        .split(is_jsx_whitespace)
+                        .next()
+                        .unwrap_or_default();
+                    parts.push(separator_without_whitespace(first_word, item, Some(next)));
+                }
+                _ => parts.push(JsxChildPart::HardLine),
+            }
+        }
+
+        parts
+    }
+}
+
+/// Splits the text into words separated by lines, surrounded by the separators to its siblings.
+fn push_text_parts<'a>(
+    text: &'a str,
+    next: Option<&JsxChildItem>,
+    parts: &mut Vec<JsxChildPart<'a>>,
+) {
+    let words = text.trim_matches(is_jsx_whitespace);
+    let leading = &text[..text.len() - text.trim_start_matches(is_jsx_whitespace).len()];
+    let trailing = &text[text.trim_end_matches(is_jsx_whitespace).len()..];
+
+    let mut words = words
+        .split(is_jsx_whitespace)
+        .filter(|word| !word.is_empty())
+        .peekable();
+
+    if !leading.is_empty() {
+        parts.push(JsxChildPart::Empty);
+
+        if leading.contains('\n') {
+            let first_word = words.peek().copied().unwrap_or_default();
+            parts.push(separator_with_whitespace(first_word));
+        } else {
+            parts.push(JsxChildPart::JsxWhitespace);
+        }
+    }
+
+    let mut last_word = None;
+    for word in words {
+        if last_word.is_some() {
+            parts.push(JsxChildPart::Line);
+        }
+        parts.push(JsxChildPart::Word(word));
+        last_word = Some(word);
+    }
+
+    // Whitespace only without a line break
+    let Some(last_word) = last_word else {
+        return;
+    };
+
+    if trailing.is_empty() {
+        parts.push(separator_without_whitespace(
+            last_word,
+            &JsxChildItem::Text(text),
+            next,
+        ));
+    } else if trailing.contains('\n') {
+        parts.push(separator_with_whitespace(last_word));
+    } else {
+        parts.push(JsxChildPart::JsxWhitespace);
+    }
+}
+
+/// The separator between two children that aren't separated by whitespace.
+fn separator_without_whitespace<'a>(
+    word: &str,
+    child: &JsxChildItem,
+    next: Option<&JsxChildItem>,
+) -> JsxChildPart<'a> {
+    let next_is_self_closing = next.is_some_and(JsxChildItem::is_self_closing_element);
+
+    if (child.is_self_closing_element() || next_is_self_closing) && word.chars().count() != 1 {
+        JsxChildPart::HardLine
+    } else {
+        JsxChildPart::SoftLine
+    }
+}
+
+/// The separator between text and a sibling if the whitespace between them contains a line break.
+fn separator_with_whitespace<'a>(word: &str) -> JsxChildPart<'a> {
+    if word.chars().count() == 1 {
+        JsxChildPart::SoftLine
+    } else {
+        JsxChildPart::HardLine
+    }
+}
+
+impl Format for FormatJsxChildren<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let Some(opening) = f.intern(&format_with(|f| self.opening.fmt(f))) else {
+            return;
+        };
+        let closing = format_with(|f| self.closing.fmt(f));
+
+        // `{" "}` is treated like text with a single space
+        let items = self
+            .children
+            .iter()
+            .map(|child| match child {
+                JSXChild::Text(text) => {
+                    let source_text = f.context().source_text();
+                    JsxChildItem::Text(
+                        &source_text[text.span.start as usize..text.span.end as usize],
+                    )
+                }
+                JSXChild::ExpressionContainer(container)
+                    if matches!(&container.expression, JSXExpression::StringLiteral(string) if string.value == " ")
+                        && f.context().comments_in(container.span).is_empty() =>
+                {
+                    JsxChildItem::Text(" ")
+                }
+                child => JsxChildItem::Node(child),
+            })
+            .collect::<Vec<_>>();
+
+        // `<div></div>`, `<div>\n</div>`
+        let is_empty = match items.as_slice() {
+            [] => true,
+            [item @ JsxChildItem::Text(_)] => !item.is_meaningful_text(),
+            _ => false,
+        };
+        if is_empty {
+            write!(f, [opening, closing]);
+            return;
+        }
+
+        // `<div>{`template`}</div>` is printed as is
+        if let [JSXChild::ExpressionContainer(container)] = self.children
+            && matches!(
+                container.expression,
+                JSXExpression::TemplateLiteral(_) | JSXExpression::TaggedTemplateExpression(_)
+            )
+        {
+            write!(f, [opening, self.children[0].format(), closing]);
+            return;
+        }
+
+        let contains_text = items.iter().any(JsxChildItem::is_meaningful_text);
+        let contains_tag = self
+            .children
+            .iter()
+            .any(|child| matches!(child, JSXChild::Element(_) | JSXChild::Fragment(_)));
+        let contains_multiple_expressions = self
+            .children
+            .iter()
+            .filter(|child| matches!(child, JSXChild::ExpressionContainer(_)))
+            .count()
+            > 1;
+
+        let mut forced_break = opening.will_break()
+            || contains_tag
+            || contains_multiple_expressions
+            || self.has_multiple_attributes;
+
+        let mut children = self.parts(&items, f);
+        remove_redundant_separators(&mut children, contains_text);
+
+        let mut multiline_children = Vec::with_capacity(children.len() + 1);
+        for (idx, child) in children.iter().enumerate() {
+            // Whitespace at the start or end of a line must be printed as `{" "}`
+            if child.is_jsx_whitespace() {
+                if idx == 1 && children[0].is_empty() {
+                    if children.len() == 2 {
+                        multiline_children.push(JsxChildPart::RawJsxWhitespace);
+                    } else {
+                        multiline_children.push(JsxChildPart::LeadingJsxWhitespace);
+                    }
+                    continue;
+                } else if idx == children.len() - 1
+                    || (idx >= 2
+                        && children[idx - 1].is_empty()
+                        && children[idx - 2].is_hard_line())
+                {
+                    multiline_children.push(JsxChildPart::RawJsxWhitespace);
+                    continue;
+                }
+            }
+
+            forced_break |= child.will_break();
+            multiline_children.push(child.clone());
+        }
+
+        // Fill expects the content to start and end with an item
+        if multiline_children.len() % 2 == 0 {
+            multiline_children.push(JsxChildPart::Empty);
+        }
+
+        let content = format_with(|f| {
+            if contains_text {
+                let mut fill = f.fill();
+                let mut parts = multiline_children.iter();
+
+                if let Some(first) = parts.next() {
+                    fill.entry(&JsxChildPart::Empty, first);
+                }
+                while let (Some(separator), Some(item)) = (parts.next(), parts.next()) {
+                    fill.entry(separator, item);
+                }
+                fill.finish();
+            } else {
+                let parts = format_with(|f| {
+                    for part in &multiline_children {
+                        write!(f, [part]);
+                    }
+                });
+                write!(f, [group(&parts).should_expand(true)]);
+            }
+        });
+
+        let multiline = format_with(|f| {
+            write!(
+                f,
+                [group(&format_args!(
+                    opening,
+                    indent(&format_args!(hard_line_break(), content)),
+                    hard_line_break(),
+                    closing
+                ))]
+            );
+        });
+
+        if forced_break {
+            write!(f, [multiline]);
+            return;
+        }
+
+        let flat = format_with(|f| {
+            let parts = format_with(|f| {
+                for part in &children {
+                    write!(f, [part]);
+                }
+            });
+            write!(f, [group(&format_args!(opening, parts, closing))]);
+        });
+
+        write!(f, [best_fitting![flat, multiline]]);
+    }
+}
+
+/// Removes empty content and line breaks next to other separators, which can result from
+/// whitespace-only text between two children.
+fn remove_redundant_separators<'a>(children: &mut Vec<JsxChildPart<'a>>, contains_text: bool) {
+    fn is<'a>(
+        children: &[JsxChildPart<'a>],
+        idx: usize,
+        predicate: fn(&JsxChildPart<'a>) -> bool,
+    ) -> bool {
+        children.get(idx).is_some_and(predicate)
+    }
+
+    let mut idx = children.len().saturating_sub(1);
+    while idx > 0 {
+        idx -= 1;
+
+        let current = &children[idx];
+        let next_is_empty = is(children, idx + 1, JsxChildPart::is_empty);
+
+        let is_pair_of_empty_strings = current.is_empty() && next_is_empty;
+        let is_pair_of_hard_lines = current.is_hard_line()
+            && next_is_empty
+            && is(children, idx + 2, JsxChildPart::is_hard_line);
+        let is_line_followed_by_jsx_whitespace = current.is_line()
+            && next_is_empty
+            && is(children, idx + 2, JsxChildPart::is_jsx_whitespace);
+        let is_jsx_whitespace_followed_by_line = current.is_jsx_whitespace()
+            && next_is_empty
+            && is(children, idx + 2, JsxChildPart::is_line);
+        let is_double_jsx_whitespace = current.is_jsx_whitespace()
+            && next_is_empty
+            && is(children, idx + 2, JsxChildPart::is_jsx_whitespace);
+        let is_pair_of_hard_or_soft_lines = (current.is_soft_line()
+            && next_is_empty
+            && is(children, idx + 2, JsxChildPart::is_hard_line))
+            || (current.is_hard_line()
+                && next_is_empty
+                && is(children, idx + 2, JsxChildPart::is_soft_line));
+
+        if (is_pair_of_hard_lines && contains_text)
+            || is_pair_of_empty_strings
+            || is_line_followed_by_jsx_whitespace
+            || is_double_jsx_whitespace
+        {
+            children.drain(idx..idx + 2);
+        } else if is_jsx_whitespace_followed_by_line || is_pair_of_hard_or_soft_lines {
+            children.drain(idx + 1..idx + 3);
+        }
+
+        idx = idx.min(children.len().saturating_sub(1));
+    }
+
+    // Line breaks around the children are added by the element itself
+    while children
+        .last()
+        .is_some_and(|child| child.is_line() || child.is_empty())
+    {
+        children.pop();
+    }
+    while children.len() > 1
+        && (children[0].is_line() || children[0].is_empty())
+        && (children[1].is_line() || children[1].is_empty())
+    {
+        children.drain(..2);
+    }
+}
+
+/// Whitespace as defined by JSX, which doesn't include non-breaking spaces.
+fn is_jsx_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\n' | '\r' | '\t')
+}
+
+impl FormatNode for JSXChild<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            JSXChild::Text(text) => {
+                let source_text = f.context().source_text();
+                write!(
+                    f,
+                    [dynamic_text(
+                        &source_text[text.span.start as usize..text.span.end as usize]
+                    )]
+                );
+            }
+            JSXChild::Element(element) => element.fmt(f),
+            JSXChild::Fragment(fragment) => fragment.fmt(f),
+            JSXChild::ExpressionContainer(container) => {
+                write!(f, [FormatJsxExpressionContainer::new(container, true)]);
+            }
+            JSXChild::Spread(spread) => spread.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for JSXSpreadChild<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("{..."), self.expression.format(), text("}")]);
+    }
+}
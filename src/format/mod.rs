@@ -1,10 +1,16 @@
+pub(crate) mod comments;
 mod js;
+mod jsx;
+mod ts;
+mod verbatim;
 
-// use oxc_ast::AstKind;
 use oxc_span::GetSpan;
 
 use crate::buffer::Buffer;
 use crate::builders::text;
+use crate::format::comments::is_suppressed;
+use crate::format::verbatim::format_suppressed_node;
+use crate::format_element::FormatElement;
 use crate::formatter::Formatter;
 use crate::write;
 
@@ -12,16 +18,40 @@ pub trait Format {
     fn fmt(&self, f: &mut Formatter);
 }
 
+impl<T: Format> Format for Option<T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter) {
+        if let Some(value) = self {
+            value.fmt(f);
+        }
+    }
+}
+
+impl<T: Format + ?Sized> Format for &T {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter) {
+        (**self).fmt(f);
+    }
+}
+
+impl Format for FormatElement {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter) {
+        f.write_element(self.clone());
+    }
+}
+
 pub trait FormatNode
 where
     Self: GetSpan,
 {
     fn fmt(&self, f: &mut Formatter) {
-        let _span = self.span();
+        let span = self.span();
 
-        // if self.is_suppressed(node, f) {
-        //     return write!(f, [format_suppressed_node(node.syntax())]);
-        // }
+        if is_suppressed(span, f) {
+            write!(f, [format_suppressed_node(span)]);
+            return;
+        }
 
         // self.fmt_leading_comments(f);
         self.fmt_node(f);
@@ -47,4 +77,24 @@ where
     fn needs_parentheses(&self) -> bool {
         false
     }
+
+    /// Returns an object that formats this node when passed to [crate::write!] or any other builder.
+    fn format(&self) -> FormatNodeRef<'_, Self>
+    where
+        Self: Sized,
+    {
+        FormatNodeRef { node: self }
+    }
+}
+
+/// Reference to a node that implements [Format] by delegating to [FormatNode::fmt].
+pub struct FormatNodeRef<'a, T> {
+    node: &'a T,
+}
+
+impl<T: FormatNode> Format for FormatNodeRef<'_, T> {
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter) {
+        self.node.fmt(f);
+    }
 }
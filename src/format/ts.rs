@@ -0,0 +1,1401 @@
+use oxc_ast::ast::*;
+use oxc_span::GetSpan;
+
+use crate::buffer::Buffer;
+use crate::builders::*;
+use crate::format::comments::FormatDanglingComments;
+use crate::format::js::{
+    FormatDirectives, FormatPropertyKey, FormatStatementList, FormatStatementSemicolon,
+    should_break_parameters, should_expand_object,
+};
+use crate::format::verbatim::format_verbatim_node;
+use crate::format::{Format, FormatNode};
+use crate::format_args;
+use crate::formatter::Formatter;
+use crate::options::Expand;
+use crate::write;
+
+impl FormatNode for TSTypeAnnotation<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text(":"), space(), self.type_annotation.format()]);
+    }
+}
+
+impl FormatNode for TSType<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            TSType::TSAnyKeyword(_) => write!(f, [text("any")]),
+            TSType::TSBigIntKeyword(_) => write!(f, [text("bigint")]),
+            TSType::TSBooleanKeyword(_) => write!(f, [text("boolean")]),
+            TSType::TSIntrinsicKeyword(_) => write!(f, [text("intrinsic")]),
+            TSType::TSNeverKeyword(_) => write!(f, [text("never")]),
+            TSType::TSNullKeyword(_) => write!(f, [text("null")]),
+            TSType::TSNumberKeyword(_) => write!(f, [text("number")]),
+            TSType::TSObjectKeyword(_) => write!(f, [text("object")]),
+            TSType::TSStringKeyword(_) => write!(f, [text("string")]),
+            TSType::TSSymbolKeyword(_) => write!(f, [text("symbol")]),
+            TSType::TSUndefinedKeyword(_) => write!(f, [text("undefined")]),
+            TSType::TSUnknownKeyword(_) => write!(f, [text("unknown")]),
+            TSType::TSVoidKeyword(_) => write!(f, [text("void")]),
+            TSType::TSThisType(_) => write!(f, [text("this")]),
+            TSType::TSArrayType(ty) => ty.fmt(f),
+            TSType::TSConditionalType(ty) => ty.fmt(f),
+            TSType::TSConstructorType(ty) => ty.fmt(f),
+            TSType::TSFunctionType(ty) => ty.fmt(f),
+            TSType::TSImportType(ty) => ty.fmt(f),
+            TSType::TSIndexedAccessType(ty) => ty.fmt(f),
+            TSType::TSInferType(ty) => ty.fmt(f),
+            TSType::TSIntersectionType(ty) => ty.fmt(f),
+            TSType::TSLiteralType(ty) => ty.fmt(f),
+            TSType::TSMappedType(ty) => ty.fmt(f),
+            TSType::TSNamedTupleMember(ty) => ty.fmt(f),
+            TSType::TSTemplateLiteralType(ty) => ty.fmt(f),
+            TSType::TSTupleType(ty) => ty.fmt(f),
+            TSType::TSTypeOperatorType(ty) => ty.fmt(f),
+            TSType::TSTypePredicate(ty) => ty.fmt(f),
+            TSType::TSTypeQuery(ty) => ty.fmt(f),
+            TSType::TSTypeReference(ty) => ty.fmt(f),
+            TSType::TSUnionType(ty) => ty.fmt(f),
+            TSType::TSParenthesizedType(ty) => ty.fmt(f),
+            TSType::TSTypeLiteral(ty) => ty.fmt(f),
+            _ => write!(f, [format_verbatim_node(self.span())]),
+        }
+    }
+}
+
+impl FormatNode for TSArrayType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [self.element_type.format(), text("[]")]);
+    }
+}
+
+impl FormatNode for TSParenthesizedType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("("), self.type_annotation.format(), text(")")]);
+    }
+}
+
+impl FormatNode for TSTypeReference<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTypeReference {
+            type_name,
+            type_parameters,
+            ..
+        } = self;
+
+        write!(
+            f,
+            [
+                type_name.format(),
+                type_parameters.as_ref().map(|params| params.format())
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSTypeName<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            TSTypeName::IdentifierReference(ident) => ident.fmt(f),
+            TSTypeName::QualifiedName(name) => name.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for TSQualifiedName<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [self.left.format(), text("."), self.right.format()]);
+    }
+}
+
+impl FormatNode for TSTypeParameterInstantiation<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTypeParameterInstantiation { params, .. } = self;
+
+        // `Promise<{ a: string }>`
+        if let [param @ (TSType::TSTypeLiteral(_) | TSType::TSMappedType(_))] = params.as_slice() {
+            write!(f, [text("<"), param.format(), text(">")]);
+            return;
+        }
+
+        let params = format_with(|f| {
+            let sep = format_with(|f| write!(f, [text(","), soft_line_break_or_space()]));
+            f.join_with(sep)
+                .entries(params.iter().map(FormatTypeInList::new))
+                .finish();
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                text("<"),
+                soft_block_indent(&params),
+                text(">")
+            ))]
+        );
+    }
+}
+
+/// Formats a type that is an entry of a list that already indents its entries, like type arguments
+/// or tuple elements. A union in such a list isn't indented a second time when it breaks.
+struct FormatTypeInList<'a, 'ast> {
+    ty: &'a TSType<'ast>,
+}
+
+impl<'a, 'ast> FormatTypeInList<'a, 'ast> {
+    fn new(ty: &'a TSType<'ast>) -> Self {
+        Self { ty }
+    }
+}
+
+impl Format for FormatTypeInList<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self.ty {
+            TSType::TSUnionType(union) => write!(f, [FormatUnionType::new(union, false)]),
+            ty => write!(f, [ty.format()]),
+        }
+    }
+}
+
+impl FormatNode for TSUnionType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [FormatUnionType::new(self, true)]);
+    }
+}
+
+/// Formats a union type on a single line, or with a leading `|` for every member when it breaks:
+///
+/// ```ts
+/// type A =
+///   | "aaaaaaaaaaaa"
+///   | "bbbbbbbbbbbb";
+/// ```
+struct FormatUnionType<'a, 'ast> {
+    union: &'a TSUnionType<'ast>,
+    should_indent: bool,
+}
+
+impl<'a, 'ast> FormatUnionType<'a, 'ast> {
+    fn new(union: &'a TSUnionType<'ast>, should_indent: bool) -> Self {
+        Self {
+            union,
+            should_indent,
+        }
+    }
+
+    /// Returns `true` for an object type combined with `null` or `void` only: `{ a: string } | null`.
+    fn should_hug(&self) -> bool {
+        let types = &self.union.types;
+        let nullish_count = types
+            .iter()
+            .filter(|ty| matches!(ty, TSType::TSNullKeyword(_) | TSType::TSVoidKeyword(_)))
+            .count();
+
+        nullish_count == types.len() - 1 && types.iter().any(is_object_type)
+    }
+}
+
+impl Format for FormatUnionType<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let types = &self.union.types;
+
+        if self.should_hug() {
+            let sep = format_with(|f| write!(f, [text(" | ")]));
+            f.join_with(sep)
+                .entries(types.iter().map(|ty| ty.format()))
+                .finish();
+            return;
+        }
+
+        let should_indent = self.should_indent;
+        let code = format_with(|f| {
+            let leading = format_with(|f| {
+                if should_indent {
+                    write!(f, [soft_line_break()]);
+                }
+                write!(f, [text("|"), space()]);
+            });
+            write!(f, [if_group_breaks(&leading)]);
+
+            let sep = format_with(|f| write!(f, [soft_line_break_or_space(), text("|"), space()]));
+            f.join_with(sep)
+                .entries(types.iter().map(|ty| ty.format()))
+                .finish();
+        });
+
+        if should_indent {
+            write!(f, [group(&indent(&code))]);
+        } else {
+            write!(f, [group(&code)]);
+        }
+    }
+}
+
+impl FormatNode for TSIntersectionType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSIntersectionType { types, .. } = self;
+
+        let content = format_with(|f| {
+            let mut was_indented = false;
+
+            for (idx, ty) in types.iter().enumerate() {
+                if idx == 0 {
+                    write!(f, [ty.format()]);
+                    continue;
+                }
+
+                let previous_is_object = is_object_type(&types[idx - 1]);
+                let is_object = is_object_type(ty);
+
+                if !previous_is_object && !is_object {
+                    // If no object is involved, go to the next line if it breaks
+                    write!(
+                        f,
+                        [indent(&format_args!(
+                            text(" &"),
+                            soft_line_break_or_space(),
+                            ty.format()
+                        ))]
+                    );
+                } else if previous_is_object && is_object {
+                    // If both are objects, don't indent
+                    write!(f, [text(" & ")]);
+                    if was_indented {
+                        write!(f, [indent(&ty.format())]);
+                    } else {
+                        write!(f, [ty.format()]);
+                    }
+                } else {
+                    // If you go from object to non-object or vice versa, then inline it
+                    if idx > 1 {
+                        was_indented = true;
+                    }
+                    write!(f, [text(" & ")]);
+                    if idx > 1 {
+                        write!(f, [indent(&ty.format())]);
+                    } else {
+                        write!(f, [ty.format()]);
+                    }
+                }
+            }
+        });
+
+        write!(f, [group(&content)]);
+    }
+}
+
+/// Object types hug the surrounding `&` and `|` instead of being indented.
+fn is_object_type(ty: &TSType) -> bool {
+    matches!(ty, TSType::TSTypeLiteral(_) | TSType::TSMappedType(_))
+}
+
+impl FormatNode for TSFunctionType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSFunctionType {
+            type_parameters,
+            this_param,
+            params,
+            return_type,
+            ..
+        } = self;
+
+        write!(
+            f,
+            [group(&format_args!(
+                type_parameters.as_ref().map(|params| params.format()),
+                FormatParameters::new(this_param.as_deref(), params),
+                space(),
+                text("=>"),
+                space(),
+                return_type.type_annotation.format()
+            ))]
+        );
+    }
+}
+
+impl FormatNode for TSConstructorType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSConstructorType {
+            r#abstract,
+            type_parameters,
+            params,
+            return_type,
+            ..
+        } = self;
+
+        if *r#abstract {
+            write!(f, [text("abstract"), space()]);
+        }
+
+        write!(f, [text("new"), space()]);
+
+        write!(
+            f,
+            [group(&format_args!(
+                type_parameters.as_ref().map(|params| params.format()),
+                params.format(),
+                space(),
+                text("=>"),
+                space(),
+                return_type.type_annotation.format()
+            ))]
+        );
+    }
+}
+
+/// Formats the parameters of a function, including a TypeScript `this` parameter: `(this: Foo, a)`.
+pub struct FormatParameters<'a, 'ast> {
+    this_param: Option<&'a TSThisParameter<'ast>>,
+    params: &'a FormalParameters<'ast>,
+}
+
+impl<'a, 'ast> FormatParameters<'a, 'ast> {
+    pub fn new(
+        this_param: Option<&'a TSThisParameter<'ast>>,
+        params: &'a FormalParameters<'ast>,
+    ) -> Self {
+        Self { this_param, params }
+    }
+}
+
+impl Format for FormatParameters<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let Some(this_param) = self.this_param else {
+            write!(f, [self.params.format()]);
+            return;
+        };
+
+        let FormalParameters { items, rest, .. } = self.params;
+
+        let parameters = format_with(|f| {
+            let sep = format_with(|f| write!(f, [text(","), soft_line_break_or_space()]));
+            let mut join = f.join_with(sep);
+            join.entry(&this_param.format());
+            join.entries(items.iter().map(|param| param.format()));
+            if let Some(rest) = rest {
+                join.entry(&rest.format());
+            }
+            join.finish();
+
+            // A rest parameter must not be followed by a comma
+            if rest.is_none() && f.options().trailing_commas().is_all() {
+                write!(f, [if_group_breaks(&text(","))]);
+            }
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                text("("),
+                soft_block_indent(&parameters),
+                text(")")
+            ))
+            .should_expand(should_break_parameters(self.params))]
+        );
+    }
+}
+
+impl FormatNode for TSThisParameter<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(
+            f,
+            [
+                text("this"),
+                self.type_annotation
+                    .as_ref()
+                    .map(|annotation| annotation.format())
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSConditionalType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [FormatConditionalType::new(self, false)]);
+    }
+}
+
+/// Formats a conditional type. A chain of conditional types is always printed over multiple lines,
+/// with every nested conditional type indented one level further.
+struct FormatConditionalType<'a, 'ast> {
+    conditional: &'a TSConditionalType<'ast>,
+    is_in_chain: bool,
+}
+
+impl<'a, 'ast> FormatConditionalType<'a, 'ast> {
+    fn new(conditional: &'a TSConditionalType<'ast>, is_in_chain: bool) -> Self {
+        Self {
+            conditional,
+            is_in_chain,
+        }
+    }
+}
+
+impl Format for FormatConditionalType<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        let TSConditionalType {
+            check_type,
+            extends_type,
+            true_type,
+            false_type,
+            ..
+        } = self.conditional;
+
+        let should_break = self.is_in_chain
+            || matches!(true_type, TSType::TSConditionalType(_))
+            || matches!(false_type, TSType::TSConditionalType(_));
+
+        write!(
+            f,
+            [group(&format_args!(
+                check_type.format(),
+                text(" extends "),
+                extends_type.format(),
+                indent(&format_args!(
+                    soft_line_break_or_space(),
+                    text("? "),
+                    FormatConditionalBranch(true_type),
+                    soft_line_break_or_space(),
+                    text(": "),
+                    FormatConditionalBranch(false_type)
+                ))
+            ))
+            .should_expand(should_break)]
+        );
+    }
+}
+
+struct FormatConditionalBranch<'a, 'ast>(&'a TSType<'ast>);
+
+impl Format for FormatConditionalBranch<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self.0 {
+            TSType::TSConditionalType(nested) => {
+                write!(f, [FormatConditionalType::new(nested, true)]);
+            }
+            ty => write!(f, [ty.format()]),
+        }
+    }
+}
+
+impl FormatNode for TSInferType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("infer"), space(), self.type_parameter.format()]);
+    }
+}
+
+impl FormatNode for TSTypeParameter<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTypeParameter {
+            name,
+            constraint,
+            default,
+            r#in,
+            out,
+            r#const,
+            ..
+        } = self;
+
+        if *r#const {
+            write!(f, [text("const"), space()]);
+        }
+        if *r#in {
+            write!(f, [text("in"), space()]);
+        }
+        if *out {
+            write!(f, [text("out"), space()]);
+        }
+
+        write!(f, [name.format()]);
+
+        if let Some(constraint) = constraint {
+            write!(f, [text(" extends "), constraint.format()]);
+        }
+
+        if let Some(default) = default {
+            write!(f, [text(" = "), default.format()]);
+        }
+    }
+}
+
+impl FormatNode for TSMappedType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSMappedType {
+            span,
+            type_parameter,
+            name_type,
+            type_annotation,
+            optional,
+            readonly,
+            ..
+        } = self;
+
+        // Like Prettier, keep the type expanded if there's a line break after the `{`
+        let source_text = f.context().source_text();
+        let should_expand =
+            source_text[span.start as usize..type_parameter.span.start as usize].contains('\n');
+
+        let key = format_with(|f| {
+            match readonly {
+                TSMappedTypeModifierOperator::True => write!(f, [text("readonly ")]),
+                TSMappedTypeModifierOperator::Plus => write!(f, [text("+readonly ")]),
+                TSMappedTypeModifierOperator::Minus => write!(f, [text("-readonly ")]),
+                TSMappedTypeModifierOperator::None => {}
+            }
+
+            let TSTypeParameter {
+                name, constraint, ..
+            } = type_parameter.as_ref();
+
+            write!(f, [text("["), name.format(), text(" in ")]);
+            if let Some(constraint) = constraint {
+                write!(f, [constraint.format()]);
+            }
+            if let Some(name_type) = name_type {
+                write!(f, [text(" as "), name_type.format()]);
+            }
+            write!(f, [text("]")]);
+
+            match optional {
+                TSMappedTypeModifierOperator::True => write!(f, [text("?")]),
+                TSMappedTypeModifierOperator::Plus => write!(f, [text("+?")]),
+                TSMappedTypeModifierOperator::Minus => write!(f, [text("-?")]),
+                TSMappedTypeModifierOperator::None => {}
+            }
+        });
+
+        let content = format_with(|f| {
+            write!(f, [group(&key)]);
+
+            if let Some(type_annotation) = type_annotation {
+                write!(f, [text(":"), space(), type_annotation.format()]);
+            }
+
+            if f.options().semicolons().is_always() {
+                write!(f, [if_group_breaks(&text(";"))]);
+            }
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                text("{"),
+                soft_block_indent_with_maybe_space(&content, f.options().bracket_spacing().value()),
+                text("}")
+            ))
+            .should_expand(should_expand)]
+        );
+    }
+}
+
+impl FormatNode for TSIndexedAccessType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(
+            f,
+            [
+                self.object_type.format(),
+                text("["),
+                self.index_type.format(),
+                text("]")
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSTypeOperator<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTypeOperator {
+            operator,
+            type_annotation,
+            ..
+        } = self;
+
+        write!(
+            f,
+            [text(operator.to_str()), space(), type_annotation.format()]
+        );
+    }
+}
+
+impl FormatNode for TSTypeQuery<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTypeQuery {
+            expr_name,
+            type_parameters,
+            ..
+        } = self;
+
+        write!(f, [text("typeof"), space()]);
+
+        match expr_name {
+            TSTypeQueryExprName::TSImportType(import) => write!(f, [import.format()]),
+            expr_name => write!(f, [expr_name.to_ts_type_name().format()]),
+        }
+
+        write!(f, [type_parameters.as_ref().map(|params| params.format())]);
+    }
+}
+
+impl FormatNode for TSImportType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSImportType {
+            is_type_of,
+            parameter,
+            qualifier,
+            attributes,
+            type_parameters,
+            ..
+        } = self;
+
+        if *is_type_of {
+            write!(f, [text("typeof"), space()]);
+        }
+
+        write!(f, [text("import("), parameter.format()]);
+
+        if let Some(attributes) = attributes {
+            write!(
+                f,
+                [text(","), space(), format_verbatim_node(attributes.span)]
+            );
+        }
+
+        write!(f, [text(")")]);
+
+        if let Some(qualifier) = qualifier {
+            write!(f, [text("."), qualifier.format()]);
+        }
+
+        write!(f, [type_parameters.as_ref().map(|params| params.format())]);
+    }
+}
+
+impl FormatNode for TSLiteralType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        match &self.literal {
+            TSLiteral::BooleanLiteral(literal) => literal.fmt(f),
+            TSLiteral::NumericLiteral(literal) => literal.fmt(f),
+            TSLiteral::BigIntLiteral(literal) => literal.fmt(f),
+            TSLiteral::StringLiteral(literal) => literal.fmt(f),
+            TSLiteral::TemplateLiteral(literal) => literal.fmt(f),
+            TSLiteral::UnaryExpression(expression) => expression.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for TSTemplateLiteralType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTemplateLiteralType { quasis, types, .. } = self;
+
+        write!(f, [text("`")]);
+
+        for (idx, quasi) in quasis.iter().enumerate() {
+            write!(f, [quasi.format()]);
+
+            if let Some(ty) = types.get(idx) {
+                write!(f, [text("${"), ty.format(), text("}")]);
+            }
+        }
+
+        write!(f, [text("`")]);
+    }
+}
+
+impl FormatNode for TSTupleType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTupleType { element_types, .. } = self;
+
+        if element_types.is_empty() {
+            write!(f, [text("[]")]);
+            return;
+        }
+
+        let elements = format_with(|f| {
+            let sep = format_with(|f| write!(f, [text(","), soft_line_break_or_space()]));
+            f.join_with(sep)
+                .entries(element_types.iter().map(|element| element.format()))
+                .finish();
+
+            // A rest element must not be followed by a comma
+            let ends_with_rest =
+                matches!(element_types.last(), Some(TSTupleElement::TSRestType(_)));
+            if !ends_with_rest && !f.options().trailing_commas().is_none() {
+                write!(f, [if_group_breaks(&text(","))]);
+            }
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                text("["),
+                soft_block_indent(&elements),
+                text("]")
+            ))]
+        );
+    }
+}
+
+impl FormatNode for TSTupleElement<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            TSTupleElement::TSOptionalType(ty) => ty.fmt(f),
+            TSTupleElement::TSRestType(ty) => ty.fmt(f),
+            element => write!(f, [FormatTypeInList::new(element.to_ts_type())]),
+        }
+    }
+}
+
+impl FormatNode for TSOptionalType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [self.type_annotation.format(), text("?")]);
+    }
+}
+
+impl FormatNode for TSRestType<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [text("..."), self.type_annotation.format()]);
+    }
+}
+
+impl FormatNode for TSNamedTupleMember<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSNamedTupleMember {
+            element_type,
+            label,
+            optional,
+            ..
+        } = self;
+
+        write!(f, [label.format()]);
+
+        if *optional {
+            write!(f, [text("?")]);
+        }
+
+        // `name?: string` is parsed as an optional type, the `?` is already printed after the label
+        match element_type {
+            TSTupleElement::TSOptionalType(ty) if *optional => {
+                write!(f, [text(":"), space(), ty.type_annotation.format()]);
+            }
+            element_type => write!(f, [text(":"), space(), element_type.format()]),
+        }
+    }
+}
+
+impl FormatNode for TSTypePredicate<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTypePredicate {
+            parameter_name,
+            asserts,
+            type_annotation,
+            ..
+        } = self;
+
+        if *asserts {
+            write!(f, [text("asserts"), space()]);
+        }
+
+        match parameter_name {
+            TSTypePredicateName::Identifier(ident) => write!(f, [ident.format()]),
+            TSTypePredicateName::This(_) => write!(f, [text("this")]),
+        }
+
+        if let Some(type_annotation) = type_annotation {
+            write!(
+                f,
+                [
+                    space(),
+                    text("is"),
+                    space(),
+                    type_annotation.type_annotation.format()
+                ]
+            );
+        }
+    }
+}
+
+impl FormatNode for TSTypeParameterDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTypeParameterDeclaration { params, .. } = self;
+
+        let params = format_with(|f| {
+            let sep = format_with(|f| write!(f, [text(","), soft_line_break_or_space()]));
+            f.join_with(sep)
+                .entries(params.iter().map(|param| param.format()))
+                .finish();
+
+            // Unlike type arguments, type parameters get a trailing comma
+            if f.options().trailing_commas().is_all() {
+                write!(f, [if_group_breaks(&text(","))]);
+            }
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                text("<"),
+                soft_block_indent(&params),
+                text(">")
+            ))]
+        );
+    }
+}
+
+impl FormatNode for TSTypeAliasDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTypeAliasDeclaration {
+            id,
+            type_parameters,
+            type_annotation,
+            declare,
+            ..
+        } = self;
+
+        if *declare {
+            write!(f, [text("declare"), space()]);
+        }
+
+        write!(
+            f,
+            [
+                text("type"),
+                space(),
+                id.format(),
+                type_parameters.as_ref().map(|params| params.format()),
+                space(),
+                text("="),
+                space(),
+                type_annotation.format(),
+                FormatStatementSemicolon
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSInterfaceDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSInterfaceDeclaration {
+            id,
+            extends,
+            type_parameters,
+            body,
+            declare,
+            ..
+        } = self;
+
+        if *declare {
+            write!(f, [text("declare"), space()]);
+        }
+
+        write!(
+            f,
+            [
+                text("interface"),
+                space(),
+                id.format(),
+                type_parameters.as_ref().map(|params| params.format())
+            ]
+        );
+
+        if let Some(extends) = extends.as_ref().filter(|extends| !extends.is_empty()) {
+            let heritage = format_with(|f| {
+                let sep = format_with(|f| write!(f, [text(","), soft_line_break_or_space()]));
+                f.join_with(sep)
+                    .entries(extends.iter().map(|heritage| heritage.format()))
+                    .finish();
+            });
+
+            write!(
+                f,
+                [group(&indent(&format_args!(
+                    soft_line_break_or_space(),
+                    text("extends"),
+                    space(),
+                    indent(&heritage)
+                )))]
+            );
+        }
+
+        write!(f, [space(), body.format()]);
+    }
+}
+
+impl FormatNode for TSInterfaceHeritage<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(
+            f,
+            [
+                self.expression.format(),
+                self.type_parameters.as_ref().map(|params| params.format())
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSInterfaceBody<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSInterfaceBody { span, body } = self;
+
+        if body.is_empty() {
+            let comments = FormatDanglingComments::in_span(*span, f);
+            if comments.is_empty() {
+                write!(f, [text("{}")]);
+            } else {
+                write!(f, [text("{"), block_indent(&comments), text("}")]);
+            }
+            return;
+        }
+
+        // Interfaces are always expanded, unless objects are collapsed: then the members are
+        // printed on one line if they fit
+        let should_expand = f.options().expand() != Expand::Never;
+
+        let members = format_with(|f| {
+            f.join_nodes_with_soft_line()
+                .entries(
+                    body.iter()
+                        .map(|member| (member.span(), FormatTypeMember::new(member, body))),
+                )
+                .finish();
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                text("{"),
+                soft_block_indent_with_maybe_space(&members, f.options().bracket_spacing().value()),
+                text("}")
+            ))
+            .should_expand(should_expand)]
+        );
+    }
+}
+
+impl FormatNode for TSTypeLiteral<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSTypeLiteral { span, members } = self;
+
+        if members.is_empty() {
+            let comments = FormatDanglingComments::in_span(*span, f);
+            if comments.is_empty() {
+                write!(f, [text("{}")]);
+            } else {
+                write!(f, [text("{"), block_indent(&comments), text("}")]);
+            }
+            return;
+        }
+
+        let should_expand = should_expand_object(span.start, members[0].span().start, f);
+
+        let content = format_with(|f| {
+            f.join_nodes_with_soft_line()
+                .entries(
+                    members
+                        .iter()
+                        .map(|member| (member.span(), FormatTypeMember::new(member, members))),
+                )
+                .finish();
+        });
+
+        write!(
+            f,
+            [group(&format_args!(
+                text("{"),
+                soft_block_indent_with_maybe_space(&content, f.options().bracket_spacing().value()),
+                text("}")
+            ))
+            .should_expand(should_expand)]
+        );
+    }
+}
+
+/// Formats a member of an interface or object type followed by its separator.
+///
+/// Members on the same line are always separated by a `;`. Members that end a line only get a
+/// `;` when [crate::options::Semicolons::Always] is configured.
+struct FormatTypeMember<'a, 'ast> {
+    member: &'a TSSignature<'ast>,
+    is_last: bool,
+}
+
+impl<'a, 'ast> FormatTypeMember<'a, 'ast> {
+    fn new(member: &'a TSSignature<'ast>, members: &'a [TSSignature<'ast>]) -> Self {
+        let is_last = members
+            .last()
+            .is_some_and(|last| std::ptr::eq(last, member));
+
+        Self { member, is_last }
+    }
+}
+
+impl Format for FormatTypeMember<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) {
+        write!(f, [self.member.format()]);
+
+        let is_always = f.options().semicolons().is_always();
+        match (self.is_last, is_always) {
+            (false, true) => write!(f, [text(";")]),
+            (false, false) => write!(f, [if_group_fits_on_line(&text(";"))]),
+            (true, true) => write!(f, [if_group_breaks(&text(";"))]),
+            (true, false) => {}
+        }
+    }
+}
+
+impl FormatNode for TSSignature<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            TSSignature::TSIndexSignature(signature) => signature.fmt(f),
+            TSSignature::TSPropertySignature(signature) => signature.fmt(f),
+            TSSignature::TSCallSignatureDeclaration(signature) => signature.fmt(f),
+            TSSignature::TSConstructSignatureDeclaration(signature) => signature.fmt(f),
+            TSSignature::TSMethodSignature(signature) => signature.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for TSPropertySignature<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSPropertySignature {
+            computed,
+            optional,
+            readonly,
+            key,
+            type_annotation,
+            ..
+        } = self;
+
+        if *readonly {
+            write!(f, [text("readonly"), space()]);
+        }
+
+        write!(f, [FormatPropertyKey::new(key, *computed)]);
+
+        if *optional {
+            write!(f, [text("?")]);
+        }
+
+        write!(
+            f,
+            [type_annotation
+                .as_ref()
+                .map(|annotation| annotation.format())]
+        );
+    }
+}
+
+impl FormatNode for TSIndexSignature<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSIndexSignature {
+            parameters,
+            type_annotation,
+            readonly,
+            r#static,
+            ..
+        } = self;
+
+        if *r#static {
+            write!(f, [text("static"), space()]);
+        }
+
+        if *readonly {
+            write!(f, [text("readonly"), space()]);
+        }
+
+        let sep = format_with(|f| write!(f, [text(","), space()]));
+        write!(f, [text("[")]);
+        f.join_with(sep)
+            .entries(parameters.iter().map(|parameter| parameter.format()))
+            .finish();
+        write!(f, [text("]"), type_annotation.format()]);
+    }
+}
+
+impl FormatNode for TSIndexSignatureName<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(
+            f,
+            [
+                dynamic_text(self.name.as_str()),
+                self.type_annotation.format()
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSCallSignatureDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSCallSignatureDeclaration {
+            type_parameters,
+            this_param,
+            params,
+            return_type,
+            ..
+        } = self;
+
+        write!(
+            f,
+            [group(&format_args!(
+                type_parameters.as_ref().map(|params| params.format()),
+                FormatParameters::new(this_param.as_ref(), params),
+                return_type.as_ref().map(|annotation| annotation.format())
+            ))]
+        );
+    }
+}
+
+impl FormatNode for TSConstructSignatureDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSConstructSignatureDeclaration {
+            type_parameters,
+            params,
+            return_type,
+            ..
+        } = self;
+
+        write!(
+            f,
+            [
+                text("new"),
+                space(),
+                group(&format_args!(
+                    type_parameters.as_ref().map(|params| params.format()),
+                    params.format(),
+                    return_type.as_ref().map(|annotation| annotation.format())
+                ))
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSMethodSignature<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSMethodSignature {
+            key,
+            computed,
+            optional,
+            kind,
+            type_parameters,
+            this_param,
+            params,
+            return_type,
+            ..
+        } = self;
+
+        match kind {
+            TSMethodSignatureKind::Method => {}
+            TSMethodSignatureKind::Get => write!(f, [text("get"), space()]),
+            TSMethodSignatureKind::Set => write!(f, [text("set"), space()]),
+        }
+
+        write!(f, [FormatPropertyKey::new(key, *computed)]);
+
+        if *optional {
+            write!(f, [text("?")]);
+        }
+
+        write!(
+            f,
+            [group(&format_args!(
+                type_parameters.as_ref().map(|params| params.format()),
+                FormatParameters::new(this_param.as_deref(), params),
+                return_type.as_ref().map(|annotation| annotation.format())
+            ))]
+        );
+    }
+}
+
+impl FormatNode for TSEnumDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSEnumDeclaration {
+            span,
+            id,
+            members,
+            r#const,
+            declare,
+            ..
+        } = self;
+
+        if *declare {
+            write!(f, [text("declare"), space()]);
+        }
+
+        if *r#const {
+            write!(f, [text("const"), space()]);
+        }
+
+        write!(f, [text("enum"), space(), id.format(), space(), text("{")]);
+
+        if members.is_empty() {
+            let comments = FormatDanglingComments::in_span(*span, f);
+            if !comments.is_empty() {
+                write!(f, [block_indent(&comments)]);
+            }
+        } else {
+            let members = format_with(|f| {
+                let mut join = f.join_nodes_with_hardline();
+                for (idx, member) in members.iter().enumerate() {
+                    let is_last = idx == members.len() - 1;
+                    let member_with_comma = format_with(|f| {
+                        write!(f, [member.format()]);
+                        if !is_last || !f.options().trailing_commas().is_none() {
+                            write!(f, [text(",")]);
+                        }
+                    });
+                    join.entry(member.span, &member_with_comma);
+                }
+                join.finish();
+            });
+            write!(f, [block_indent(&members)]);
+        }
+
+        write!(f, [text("}")]);
+    }
+}
+
+impl FormatNode for TSEnumMember<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSEnumMember {
+            id, initializer, ..
+        } = self;
+
+        match id {
+            TSEnumMemberName::Identifier(ident) => write!(f, [ident.format()]),
+            TSEnumMemberName::String(string) => write!(f, [string.format()]),
+        }
+
+        if let Some(initializer) = initializer {
+            write!(f, [space(), text("="), space(), initializer.format()]);
+        }
+    }
+}
+
+impl FormatNode for TSModuleDeclaration<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSModuleDeclaration {
+            id,
+            body,
+            kind,
+            declare,
+            ..
+        } = self;
+
+        if *declare {
+            write!(f, [text("declare"), space()]);
+        }
+
+        match kind {
+            // The name of `declare global {}` is the `global` keyword itself
+            TSModuleDeclarationKind::Global => write!(f, [text("global")]),
+            TSModuleDeclarationKind::Module => write!(f, [text("module"), space(), id.format()]),
+            TSModuleDeclarationKind::Namespace => {
+                write!(f, [text("namespace"), space(), id.format()]);
+            }
+        }
+
+        // `namespace A.B.C {}` is parsed as nested module declarations
+        let mut body = body.as_ref();
+        while let Some(TSModuleDeclarationBody::TSModuleDeclaration(nested)) = body {
+            write!(f, [text("."), nested.id.format()]);
+            body = nested.body.as_ref();
+        }
+
+        match body {
+            Some(TSModuleDeclarationBody::TSModuleBlock(block)) => {
+                write!(f, [space(), block.format()]);
+            }
+            _ => write!(f, [FormatStatementSemicolon]),
+        }
+    }
+}
+
+impl FormatNode for TSModuleDeclarationName<'_> {
+    fn fmt(&self, f: &mut Formatter) {
+        match self {
+            TSModuleDeclarationName::Identifier(ident) => ident.fmt(f),
+            TSModuleDeclarationName::StringLiteral(string) => string.fmt(f),
+        }
+    }
+}
+
+impl FormatNode for TSModuleBlock<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        let TSModuleBlock {
+            span,
+            directives,
+            body,
+        } = self;
+
+        write!(f, [text("{")]);
+
+        if directives.is_empty() && body.is_empty() {
+            let comments = FormatDanglingComments::in_span(*span, f);
+            if !comments.is_empty() {
+                write!(f, [block_indent(&comments)]);
+            }
+        } else {
+            let first_statement = body.first().map(GetSpan::span);
+            write!(
+                f,
+                [block_indent(&format_args!(
+                    FormatDirectives::new(directives, first_statement),
+                    FormatStatementList::new(body)
+                ))]
+            );
+        }
+
+        write!(f, [text("}")]);
+    }
+}
+
+impl FormatNode for TSAsExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(
+            f,
+            [
+                self.expression.format(),
+                text(" as "),
+                self.type_annotation.format()
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSSatisfiesExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(
+            f,
+            [
+                self.expression.format(),
+                text(" satisfies "),
+                self.type_annotation.format()
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSNonNullExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [self.expression.format(), text("!")]);
+    }
+}
+
+impl FormatNode for TSTypeAssertion<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(
+            f,
+            [
+                text("<"),
+                self.type_annotation.format(),
+                text(">"),
+                self.expression.format()
+            ]
+        );
+    }
+}
+
+impl FormatNode for TSInstantiationExpression<'_> {
+    fn fmt_fields(&self, f: &mut Formatter) {
+        write!(f, [self.expression.format(), self.type_parameters.format()]);
+    }
+}
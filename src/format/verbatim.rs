@@ -0,0 +1,63 @@
+use oxc_span::Span;
+
+use crate::buffer::Buffer;
+use crate::builders::*;
+use crate::format::Format;
+use crate::format::comments::FormatLeadingComments;
+use crate::format_element::tag::{Tag, VerbatimKind};
+use crate::format_element::{FormatElement, normalize_newlines};
+use crate::formatter::Formatter;
+use crate::write;
+
+/// Formats a node that is suppressed by a `// oxc-formatter-ignore` or `// prettier-ignore`
+/// comment. The comments before the node are kept and the node is printed as it is written in the
+/// source text.
+pub fn format_suppressed_node(span: Span) -> FormatVerbatimNode {
+    FormatVerbatimNode {
+        span,
+        kind: VerbatimKind::Suppressed,
+        with_leading_comments: true,
+    }
+}
+
+/// Formats a node that has no formatting implementation by printing it as it is written in the
+/// source text, only normalizing its line endings.
+pub fn format_verbatim_node(span: Span) -> FormatVerbatimNode {
+    FormatVerbatimNode {
+        span,
+        kind: VerbatimKind::Verbatim,
+        with_leading_comments: false,
+    }
+}
+
+/// Prints the source text of a node as it is, marked with the [VerbatimKind] it's printed for.
+pub struct FormatVerbatimNode {
+    span: Span,
+    kind: VerbatimKind,
+    with_leading_comments: bool,
+}
+
+impl FormatVerbatimNode {
+    /// Doesn't print the comments before the node, e.g. because the suppression comment is
+    /// inside of a preceding JSX expression container: `{/* prettier-ignore */}`.
+    pub fn without_leading_comments(mut self) -> Self {
+        self.with_leading_comments = false;
+        self
+    }
+}
+
+impl Format for FormatVerbatimNode {
+    fn fmt(&self, f: &mut Formatter) {
+        if self.with_leading_comments {
+            let comments = FormatLeadingComments::before(self.span, f);
+            write!(f, [comments]);
+        }
+
+        let source_text = f.context().source_text();
+        let text = &source_text[self.span.start as usize..self.span.end as usize];
+
+        f.write_element(FormatElement::Tag(Tag::StartVerbatim(self.kind)));
+        write!(f, [dynamic_text(&normalize_newlines(text, ['\r']))]);
+        f.write_element(FormatElement::Tag(Tag::EndVerbatim));
+    }
+}
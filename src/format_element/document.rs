@@ -7,6 +7,7 @@ use rustc_hash::FxHashMap;
 use crate::format_element::FormatElement;
 use crate::format_element::elements::*;
 use crate::format_element::tag::*;
+use crate::printer::{PrintWidth, Printer, PrinterOptions};
 
 /// A formatted document.
 #[derive(Debug, Clone, Eq, PartialEq, Default)]
@@ -264,3 +265,277 @@ impl FormatElements for [FormatElement] {
         self.last().and_then(|element| element.end_tag(kind))
     }
 }
+
+impl Document {
+    /// Returns the IR of this document as readable pseudo-code, e.g.
+    /// `group(["let", space, indent([soft_line_break, ...])])`.
+    ///
+    /// The pseudo-code is itself an IR document that gets printed by the [Printer], so that
+    /// large documents stay readable.
+    pub fn to_ir_string(&self) -> String {
+        let mut writer = IrWriter::default();
+        writer.write_elements(&self.elements);
+
+        let mut document = Document::from(writer.elements);
+        document.propagate_expand();
+
+        // Always use the default width of 80, regardless of the options of the formatted document
+        let options = PrinterOptions::default().with_print_width(PrintWidth::new(80));
+        Printer::new(options)
+            .print(&document)
+            .expect("Document of the IR to be valid")
+    }
+}
+
+impl std::fmt::Display for Document {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_ir_string())
+    }
+}
+
+/// Builds the IR document representing the pseudo-code of another IR document.
+#[derive(Default)]
+struct IrWriter {
+    elements: Vec<FormatElement>,
+    /// Index of every interned element that has already been printed, used to print
+    /// further occurrences as references.
+    printed_interned: FxHashMap<Interned, usize>,
+}
+
+impl IrWriter {
+    fn text(&mut self, text: &'static str) {
+        self.elements.push(FormatElement::StaticText { text });
+    }
+
+    fn dynamic_text(&mut self, text: String) {
+        self.elements
+            .push(FormatElement::DynamicText { text: text.into() });
+    }
+
+    fn separator(&mut self) {
+        self.text(",");
+        self.elements
+            .push(FormatElement::Line(LineMode::SoftOrSpace));
+    }
+
+    fn content_array_start(&mut self) {
+        self.text("[");
+        self.elements.extend([
+            FormatElement::Tag(Tag::StartGroup(Group::new())),
+            FormatElement::Tag(Tag::StartIndent),
+            FormatElement::Line(LineMode::Soft),
+        ]);
+    }
+
+    fn content_array_end(&mut self) {
+        self.elements.extend([
+            FormatElement::Tag(Tag::EndIndent),
+            FormatElement::Line(LineMode::Soft),
+            FormatElement::Tag(Tag::EndGroup),
+        ]);
+        self.text("]");
+    }
+
+    fn write_elements(&mut self, elements: &[FormatElement]) {
+        self.content_array_start();
+
+        let mut tag_stack = Vec::new();
+        let mut first_element = true;
+        let mut iter = elements.iter().peekable();
+
+        while let Some(element) = iter.next() {
+            if !first_element && !element.is_end_tag() {
+                self.separator();
+            }
+            first_element = false;
+
+            match element {
+                FormatElement::Space
+                | FormatElement::HardSpace
+                | FormatElement::StaticText { .. }
+                | FormatElement::DynamicText { .. } => {
+                    // Merge adjacent texts and spaces into a single string
+                    let mut run = String::new();
+                    let mut current = Some(element);
+
+                    while let Some(element) = current {
+                        match element {
+                            FormatElement::Space | FormatElement::HardSpace => run.push(' '),
+                            FormatElement::StaticText { text } => run.push_str(text),
+                            FormatElement::DynamicText { text } => run.push_str(text),
+                            _ => unreachable!(),
+                        }
+
+                        current = iter.next_if(|next| next.is_text() || next.is_space());
+                    }
+
+                    if run == " " {
+                        self.text("space");
+                    } else {
+                        self.dynamic_text(format!("{run:?}"));
+                    }
+                }
+                FormatElement::Line(mode) => self.text(match mode {
+                    LineMode::SoftOrSpace => "soft_line_break_or_space",
+                    LineMode::Soft => "soft_line_break",
+                    LineMode::Hard => "hard_line_break",
+                    LineMode::Empty => "empty_line",
+                }),
+                FormatElement::ExpandParent => self.text("expand_parent"),
+                FormatElement::LineSuffixBoundary => self.text("line_suffix_boundary"),
+                FormatElement::BestFitting(best_fitting) => {
+                    self.text("best_fitting([");
+                    self.elements.extend([
+                        FormatElement::Tag(Tag::StartIndent),
+                        FormatElement::Line(LineMode::Hard),
+                    ]);
+
+                    for (index, variant) in best_fitting.variants().iter().enumerate() {
+                        if index > 0 {
+                            self.text(",");
+                            self.elements.push(FormatElement::Line(LineMode::Hard));
+                        }
+                        self.write_elements(variant);
+                    }
+
+                    self.elements.extend([
+                        FormatElement::Tag(Tag::EndIndent),
+                        FormatElement::Line(LineMode::Hard),
+                    ]);
+                    self.text("])");
+                }
+                FormatElement::Interned(interned) => {
+                    match self.printed_interned.get(interned).copied() {
+                        None => {
+                            let index = self.printed_interned.len();
+                            self.printed_interned.insert(interned.clone(), index);
+                            self.dynamic_text(format!("<interned {index}>"));
+                            self.elements.push(FormatElement::Space);
+                            self.write_elements(interned);
+                        }
+                        Some(index) => {
+                            self.dynamic_text(format!("<ref interned *{index}>"));
+                        }
+                    }
+                }
+                FormatElement::Tag(tag) => {
+                    if tag.is_start() {
+                        first_element = true;
+                        tag_stack.push(tag.kind());
+                    } else if tag_stack.last() == Some(&tag.kind()) {
+                        tag_stack.pop();
+                    } else {
+                        // Keep the document printable even if it contains unbalanced tags
+                        self.dynamic_text(format!("<END_TAG_WITHOUT_START<{:?}>>", tag.kind()));
+                        continue;
+                    }
+
+                    match tag {
+                        Tag::StartIndent => self.text("indent("),
+                        Tag::StartAlign(align) => {
+                            self.dynamic_text(format!("align({},", align.count()));
+                            self.elements.push(FormatElement::Space);
+                        }
+                        Tag::StartDedent(mode) => self.text(match mode {
+                            DedentMode::Level => "dedent(",
+                            DedentMode::Root => "dedent_to_root(",
+                        }),
+                        Tag::StartGroup(group) => {
+                            self.text("group(");
+                            if let Some(id) = group.id() {
+                                self.dynamic_text(format!("\"{id:?}\","));
+                                self.elements.push(FormatElement::Space);
+                            }
+                            match group.mode() {
+                                GroupMode::Flat => {}
+                                GroupMode::Expand => {
+                                    self.text("expand: true,");
+                                    self.elements.push(FormatElement::Space);
+                                }
+                                GroupMode::Propagated => {
+                                    self.text("expand: propagated,");
+                                    self.elements.push(FormatElement::Space);
+                                }
+                            }
+                        }
+                        Tag::StartConditionalContent(condition) => {
+                            self.text(match condition.mode() {
+                                PrintMode::Flat => "if_group_fits_on_line(",
+                                PrintMode::Expanded => "if_group_breaks(",
+                            });
+                            if let Some(id) = condition.group_id {
+                                self.dynamic_text(format!("\"{id:?}\","));
+                                self.elements.push(FormatElement::Space);
+                            }
+                        }
+                        Tag::StartIndentIfGroupBreaks(id) => {
+                            self.dynamic_text(format!("indent_if_group_breaks(\"{id:?}\","));
+                            self.elements.push(FormatElement::Space);
+                        }
+                        Tag::StartFill => self.text("fill("),
+                        Tag::StartEntry => {}
+                        Tag::StartLineSuffix => self.text("line_suffix("),
+                        Tag::StartVerbatim(_) => self.text("verbatim("),
+                        Tag::StartLabelled(label) => {
+                            self.dynamic_text(format!("label(\"{label:?}\","));
+                            self.elements.push(FormatElement::Space);
+                        }
+                        Tag::EndEntry => self.content_array_end(),
+                        Tag::EndIndent
+                        | Tag::EndAlign
+                        | Tag::EndDedent(_)
+                        | Tag::EndGroup
+                        | Tag::EndConditionalContent
+                        | Tag::EndIndentIfGroupBreaks(_)
+                        | Tag::EndFill
+                        | Tag::EndLineSuffix
+                        | Tag::EndVerbatim
+                        | Tag::EndLabelled => {
+                            self.content_array_end();
+                            self.text(")");
+                        }
+                    }
+
+                    if tag.is_start() {
+                        self.content_array_start();
+                    }
+                }
+            }
+        }
+
+        while let Some(kind) = tag_stack.pop() {
+            self.content_array_end();
+            self.text(")");
+            self.separator();
+            self.dynamic_text(format!("<START_WITHOUT_END<{kind:?}>>"));
+        }
+
+        self.content_array_end();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ir_string() {
+        let document = Document::from(vec![
+            FormatElement::StaticText { text: "let" },
+            FormatElement::Space,
+            FormatElement::Tag(Tag::StartGroup(Group::new())),
+            FormatElement::Tag(Tag::StartIndent),
+            FormatElement::Line(LineMode::Soft),
+            FormatElement::DynamicText {
+                text: "\"a\"".into(),
+            },
+            FormatElement::Tag(Tag::EndIndent),
+            FormatElement::Tag(Tag::EndGroup),
+        ]);
+
+        assert_eq!(
+            document.to_ir_string(),
+            r#"["let ", group([indent([soft_line_break, "\"a\""])])]"#
+        );
+    }
+}
@@ -236,7 +236,7 @@ impl Align {
     }
 }
 
-#[derive(Debug, Eq, Copy, Clone)]
+#[derive(Eq, Copy, Clone)]
 pub struct LabelId {
     value: u64,
     #[cfg(debug_assertions)]
@@ -261,6 +261,20 @@ impl PartialEq for LabelId {
     }
 }
 
+impl std::fmt::Debug for LabelId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[cfg(debug_assertions)]
+        {
+            write!(f, "#{}", self.name)
+        }
+
+        #[cfg(not(debug_assertions))]
+        {
+            write!(f, "#{}", self.value)
+        }
+    }
+}
+
 impl LabelId {
     pub fn of<T: Label>(label: T) -> Self {
         Self {
@@ -1,38 +1,90 @@
 use crate::arguments::Arguments;
-use crate::buffer::{Buffer, BufferSnapshot};
+use crate::buffer::{Buffer, BufferSnapshot, VecBuffer};
+use crate::builders::{
+    FillBuilder, JoinBuilder, JoinNodesBuilder, Line, hard_line_break, soft_line_break_or_space,
+};
 use crate::context::FormatContext;
-use crate::format_element::FormatElement;
+use crate::format::Format;
+use crate::format_element::{FormatElement, Interned};
 use crate::options::FormatOptions;
 use crate::state::FormatState;
 
-pub struct Formatter<'a> {
-    buffer: &'a mut dyn Buffer,
+pub struct Formatter<'buf, 'ast> {
+    buffer: &'buf mut dyn Buffer<'ast>,
 }
 
-impl<'a> Formatter<'a> {
-    pub fn new(buffer: &'a mut (dyn Buffer + 'a)) -> Self {
+impl<'buf, 'ast> Formatter<'buf, 'ast> {
+    pub fn new(buffer: &'buf mut (dyn Buffer<'ast> + 'buf)) -> Self {
         Self { buffer }
     }
 }
 
-impl<'a> Formatter<'a> {
+impl<'buf, 'ast> Formatter<'buf, 'ast> {
     /// Returns the format options
     pub fn options(&self) -> &FormatOptions {
         self.context().options()
     }
 
     /// Returns the Context specifying how to format the current CST
-    pub fn context(&self) -> &FormatContext {
+    pub fn context(&self) -> &FormatContext<'ast> {
         self.state().context()
     }
 
     /// Returns a mutable reference to the context.
-    pub fn context_mut(&mut self) -> &mut FormatContext {
+    pub fn context_mut(&mut self) -> &mut FormatContext<'ast> {
         self.state_mut().context_mut()
     }
+
+    /// Joins multiple [Format] together with the passed `joiner` between every two entries.
+    pub fn join_with<'fmt, Joiner: Format>(
+        &'fmt mut self,
+        joiner: Joiner,
+    ) -> JoinBuilder<'fmt, 'buf, 'ast, Joiner> {
+        JoinBuilder::with_separator(self, joiner)
+    }
+
+    /// Specialized version of [Formatter::join_with] for joining SyntaxNodes separated by one or more
+    /// line breaks depending on the input file.
+    pub fn join_nodes_with_hardline<'fmt>(
+        &'fmt mut self,
+    ) -> JoinNodesBuilder<'fmt, 'buf, 'ast, Line> {
+        JoinNodesBuilder::new(hard_line_break(), self)
+    }
+
+    /// Specialized version of [Formatter::join_with] for joining SyntaxNodes separated by a space, soft
+    /// line break or empty line depending on the input file.
+    pub fn join_nodes_with_soft_line<'fmt>(
+        &'fmt mut self,
+    ) -> JoinNodesBuilder<'fmt, 'buf, 'ast, Line> {
+        JoinNodesBuilder::new(soft_line_break_or_space(), self)
+    }
+
+    /// Concatenates a list of [crate::Format] objects with spaces and line breaks to fit
+    /// them on as few lines as possible. Each element introduces a conceptual group. The printer
+    /// first tries to print the item in flat mode but then prints it in expanded mode if it doesn't fit.
+    pub fn fill<'fmt>(&'fmt mut self) -> FillBuilder<'fmt, 'buf, 'ast> {
+        FillBuilder::new(self)
+    }
+
+    /// Formats `content` into an interned element without writing it to the formatter's buffer.
+    ///
+    /// The element can be written multiple times, e.g. in different variants of a
+    /// [crate::best_fitting!], and allows to test if the content [will break](crate::format_element::FormatElements::will_break).
+    pub fn intern(&mut self, content: &dyn Format) -> Option<FormatElement> {
+        let mut buffer = VecBuffer::new(self.state_mut());
+        content.fmt(&mut Formatter::new(&mut buffer));
+        let mut elements = buffer.into_vec();
+
+        match elements.len() {
+            0 => None,
+            // Doesn't get cheaper than calling clone, use the element directly
+            1 => elements.pop(),
+            _ => Some(FormatElement::Interned(Interned::new(elements))),
+        }
+    }
 }
 
-impl Buffer for Formatter<'_> {
+impl<'ast> Buffer<'ast> for Formatter<'_, 'ast> {
     #[inline(always)]
     fn write_element(&mut self, element: FormatElement) {
         self.buffer.write_element(element);
@@ -49,11 +101,11 @@ impl Buffer for Formatter<'_> {
         }
     }
 
-    fn state(&self) -> &FormatState {
+    fn state(&self) -> &FormatState<'ast> {
         self.buffer.state()
     }
 
-    fn state_mut(&mut self) -> &mut FormatState {
+    fn state_mut(&mut self) -> &mut FormatState<'ast> {
         self.buffer.state_mut()
     }
 
@@ -1,6 +1,8 @@
 mod arguments;
 mod buffer;
 mod builders;
+pub mod cli;
+mod config;
 mod context;
 mod format;
 mod format_element;
@@ -8,20 +10,27 @@ mod formatter;
 mod group_id;
 mod macros;
 mod options;
+mod pragma;
 mod printer;
 mod state;
 
 use oxc_allocator::Allocator;
+use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 
 use arguments::Arguments;
 use buffer::{Buffer, VecBuffer};
+pub use config::{ConfigError, ConfigResolver, FormatConfig};
 use context::FormatContext;
 use format::FormatNode;
 use format_element::document::Document;
 use formatter::Formatter;
-pub use options::FormatOptions;
+pub use options::{
+    AmbiguousWidth, ArrowParentheses, AttributePosition, BracketSameLine, BracketSpacing, Expand,
+    FormatOptions, IndentStyle, IndentWidth, LineEnding, LineWidth, ObjectWrap, QuoteProperties,
+    QuoteStyle, Semicolons, TrailingCommas,
+};
 use printer::Printer;
 use state::FormatState;
 
@@ -35,7 +44,7 @@ type FormatResult<T> = Result<T, FormatError>;
 // ---
 
 #[inline(always)]
-fn write_with_formatter(output: &mut dyn Buffer, args: Arguments) {
+fn write_with_formatter<'ast>(output: &mut dyn Buffer<'ast>, args: Arguments) {
     let mut f = Formatter::new(output);
     f.write_fmt(args);
 }
@@ -48,17 +57,41 @@ pub fn format_source(
     options: FormatOptions,
 ) -> FormatResult<String> {
     let allocator = Allocator::new();
+    format_source_with_allocator(&allocator, source_text, source_type, options)
+}
 
+/// Same as [format_source], but builds the AST in the given allocator. Resetting and reusing
+/// one allocator for many files avoids allocating new memory for each of them.
+pub fn format_source_with_allocator(
+    allocator: &Allocator,
+    source_text: &str,
+    source_type: SourceType,
+    options: FormatOptions,
+) -> FormatResult<String> {
     // TEXT -> AST
     let parser =
-        Parser::new(&allocator, source_text, source_type).with_options(ParseOptions::default());
+        Parser::new(allocator, source_text, source_type).with_options(ParseOptions::default());
     let parsed = parser.parse();
+    if parsed.panicked || !parsed.errors.is_empty() {
+        return Err(format_parse_errors(source_text, &parsed.errors));
+    }
     let program = parsed.program;
 
+    let is_ignored = pragma::has_pragma(&program, pragma::IGNORE_PRAGMA)
+        || options.require_pragma()
+            && !pragma::FORMAT_PRAGMAS
+                .iter()
+                .any(|pragma| pragma::has_docblock_pragma(&program, pragma));
+    if is_ignored {
+        return Ok(source_text.to_string());
+    }
+
     // TODO: Transform AST
 
-    // TODO: How to share `source_text` around?
-    let mut state = FormatState::new(FormatContext::new(options));
+    let line_ending = options.line_ending().resolve(source_text);
+    let options = options.with_line_ending(line_ending);
+    let context = FormatContext::new(options, program.source_text, source_type, &program.comments);
+    let mut state = FormatState::new(context);
     let mut buffer = VecBuffer::new(&mut state);
 
     // AST -> IR
@@ -67,9 +100,46 @@ pub fn format_source(
     let mut document = Document::from(buffer.into_vec());
     document.propagate_expand();
 
+    let context = state.into_context();
+    if context.options().print_ir() {
+        return Ok(document.to_ir_string());
+    }
+
     // IR -> TEXT
-    let printer = Printer::new(state.into_context().options().as_print_options());
+    let printer = Printer::new(context.options().as_print_options());
     let printed = printer.print(&document)?;
 
     Ok(printed)
 }
+
+/// Renders the parser errors as `line:column: message`, one per line.
+fn format_parse_errors(source_text: &str, errors: &[OxcDiagnostic]) -> FormatError {
+    if errors.is_empty() {
+        return "Failed to parse the source text".to_string();
+    }
+
+    errors
+        .iter()
+        .map(|error| {
+            let offset = error
+                .labels
+                .as_ref()
+                .and_then(|labels| labels.first())
+                .map(|label| label.offset());
+            match offset {
+                Some(offset) => {
+                    let before = &source_text[..offset.min(source_text.len())];
+                    let line = before.matches('\n').count() + 1;
+                    let column = before
+                        .rsplit('\n')
+                        .next()
+                        .map_or(0, |line| line.chars().count())
+                        + 1;
+                    format!("{line}:{column}: {error}")
+                }
+                None => error.to_string(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -1,25 +1,19 @@
-use oxc_formatter::{FormatOptions, format_source};
-use oxc_span::SourceType;
+use std::process::ExitCode;
 
-fn main() {
-    let source_text = "let a, b='Hey';const c =   [2,3,4]   ; call()";
-    let source_type = SourceType::mjs();
+use bpaf::Args;
+use oxc_formatter::cli::{EXIT_ERROR, FormatRunner, format_command};
 
-    let options = FormatOptions::default();
-    println!("🛠️ Format with options:");
-    println!("{options:?}");
-
-    println!("👀 Original code:");
-    println!("{source_text}");
-
-    match format_source(source_text, source_type, options) {
-        Ok(formatted) => {
-            println!("✨ Formatted code:");
-            println!("{}", formatted);
-        }
+fn main() -> ExitCode {
+    match format_command().run_inner(Args::current_args()) {
+        Ok(command) => FormatRunner::new(command).run(),
         Err(err) => {
-            eprintln!("💥 Failed to format:");
-            eprintln!("{err}");
+            err.print_message(100);
+            // Invalid arguments use the same exit code as any other error
+            if err.exit_code() == 0 {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::from(EXIT_ERROR)
+            }
         }
-    };
+    }
 }
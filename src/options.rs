@@ -4,48 +4,223 @@ use std::str::FromStr;
 
 use crate::printer::PrinterOptions;
 
+/// The options of the formatter.
+///
+/// With the `serde` feature, the options are (de)serialized using the names of the Prettier
+/// options. They are deserialized like the options of a configuration file, see
+/// [FormatOptions::from_json].
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(rename_all = "camelCase")
+)]
 pub struct FormatOptions {
     /// The indent style.
-    indent_style: IndentStyle,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "useTabs", serialize_with = "prettier_bool::serialize")
+    )]
+    pub(crate) indent_style: IndentStyle,
     /// The indent width.
-    indent_width: IndentWidth,
+    #[cfg_attr(feature = "serde", serde(rename = "tabWidth"))]
+    pub(crate) indent_width: IndentWidth,
     /// The type of line ending.
-    line_ending: LineEnding,
+    #[cfg_attr(feature = "serde", serde(rename = "endOfLine"))]
+    pub(crate) line_ending: LineEnding,
     /// What's the max width of a line. Defaults to 80.
-    line_width: LineWidth,
+    #[cfg_attr(feature = "serde", serde(rename = "printWidth"))]
+    pub(crate) line_width: LineWidth,
     /// The style for quotes. Defaults to double.
-    quote_style: QuoteStyle,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "singleQuote", serialize_with = "prettier_bool::serialize")
+    )]
+    pub(crate) quote_style: QuoteStyle,
     /// The style for JSX quotes. Defaults to double.
-    jsx_quote_style: QuoteStyle,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "jsxSingleQuote", serialize_with = "prettier_bool::serialize")
+    )]
+    pub(crate) jsx_quote_style: QuoteStyle,
     /// When properties in objects are quoted. Defaults to as-needed.
-    quote_properties: QuoteProperties,
+    #[cfg_attr(feature = "serde", serde(rename = "quoteProps"))]
+    pub(crate) quote_properties: QuoteProperties,
     /// Print trailing commas wherever possible in multi-line comma-separated syntactic structures. Defaults to "all".
-    trailing_commas: TrailingCommas,
+    #[cfg_attr(feature = "serde", serde(rename = "trailingComma"))]
+    pub(crate) trailing_commas: TrailingCommas,
     /// Whether the formatter prints semicolons for all statements, class members, and type members or only when necessary because of [ASI](https://tc39.es/ecma262/multipage/ecmascript-language-lexical-grammar.html#sec-automatic-semicolon-insertion).
-    semicolons: Semicolons,
+    #[cfg_attr(
+        feature = "serde",
+        serde(rename = "semi", serialize_with = "prettier_bool::serialize")
+    )]
+    pub(crate) semicolons: Semicolons,
     /// Whether to add non-necessary parentheses to arrow functions. Defaults to "always".
-    arrow_parentheses: ArrowParentheses,
+    #[cfg_attr(feature = "serde", serde(rename = "arrowParens"))]
+    pub(crate) arrow_parentheses: ArrowParentheses,
     /// Whether to insert spaces around brackets in object literals. Defaults to true.
-    bracket_spacing: BracketSpacing,
+    pub(crate) bracket_spacing: BracketSpacing,
     /// Whether to hug the closing bracket of multiline HTML/JSX tags to the end of the last line, rather than being alone on the following line. Defaults to false.
-    bracket_same_line: BracketSameLine,
+    pub(crate) bracket_same_line: BracketSameLine,
     /// Attribute position style. By default auto.
-    attribute_position: AttributePosition,
-    /// Whether to expand object and array literals to multiple lines. Defaults to "auto".
-    expand: Expand,
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            rename = "singleAttributePerLine",
+            serialize_with = "prettier_bool::serialize"
+        )
+    )]
+    pub(crate) attribute_position: AttributePosition,
+    /// Whether to expand object literals, object types, interfaces and import/export lists to
+    /// multiple lines. Defaults to "auto".
+    #[cfg_attr(
+        feature = "serde",
+        serde(flatten, serialize_with = "ObjectWrap::serialize_expand")
+    )]
+    pub(crate) expand: Expand,
+    /// The width of the characters whose width is ambiguous in East Asian typography. Defaults to narrow.
+    pub(crate) ambiguous_width: AmbiguousWidth,
+    /// Only format files that have a `@format` or `@prettier` pragma in their top docblock. Defaults to false.
+    pub(crate) require_pragma: bool,
+    /// Insert a `@format` pragma into the top docblock of formatted files. Defaults to false.
+    pub(crate) insert_pragma: bool,
+    /// Output the IR of the formatted document as pseudo-code instead of the formatted code. Defaults to false.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) print_ir: bool,
 }
 
 impl FormatOptions {
+    pub fn with_indent_style(mut self, indent_style: IndentStyle) -> Self {
+        self.indent_style = indent_style;
+        self
+    }
+
+    pub fn with_indent_width(mut self, indent_width: IndentWidth) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_line_width(mut self, line_width: LineWidth) -> Self {
+        self.line_width = line_width;
+        self
+    }
+
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    pub fn with_jsx_quote_style(mut self, jsx_quote_style: QuoteStyle) -> Self {
+        self.jsx_quote_style = jsx_quote_style;
+        self
+    }
+
+    pub fn with_quote_properties(mut self, quote_properties: QuoteProperties) -> Self {
+        self.quote_properties = quote_properties;
+        self
+    }
+
+    pub fn with_trailing_commas(mut self, trailing_commas: TrailingCommas) -> Self {
+        self.trailing_commas = trailing_commas;
+        self
+    }
+
+    pub fn with_semicolons(mut self, semicolons: Semicolons) -> Self {
+        self.semicolons = semicolons;
+        self
+    }
+
+    pub fn with_arrow_parentheses(mut self, arrow_parentheses: ArrowParentheses) -> Self {
+        self.arrow_parentheses = arrow_parentheses;
+        self
+    }
+
+    pub fn with_bracket_spacing(mut self, bracket_spacing: BracketSpacing) -> Self {
+        self.bracket_spacing = bracket_spacing;
+        self
+    }
+
+    pub fn with_bracket_same_line(mut self, bracket_same_line: BracketSameLine) -> Self {
+        self.bracket_same_line = bracket_same_line;
+        self
+    }
+
+    pub fn with_attribute_position(mut self, attribute_position: AttributePosition) -> Self {
+        self.attribute_position = attribute_position;
+        self
+    }
+
+    pub fn with_expand(mut self, expand: Expand) -> Self {
+        self.expand = expand;
+        self
+    }
+
+    pub fn with_ambiguous_width(mut self, ambiguous_width: AmbiguousWidth) -> Self {
+        self.ambiguous_width = ambiguous_width;
+        self
+    }
+
+    pub fn with_require_pragma(mut self, require_pragma: bool) -> Self {
+        self.require_pragma = require_pragma;
+        self
+    }
+
+    pub fn with_insert_pragma(mut self, insert_pragma: bool) -> Self {
+        self.insert_pragma = insert_pragma;
+        self
+    }
+
+    pub fn with_print_ir(mut self, print_ir: bool) -> Self {
+        self.print_ir = print_ir;
+        self
+    }
+
+    /// Reads the options from a JSON object using the names of the Prettier options, e.g.
+    /// `{ "printWidth": 100, "semi": false }`. Missing options keep their default value.
+    ///
+    /// The object is read like a configuration file, so the same options are accepted. Its
+    /// `overrides` only apply to the files formatted with a [crate::ConfigResolver]
+    /// and are ignored.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the options of the printer.
+    ///
+    /// A [LineEnding::Auto] line ending is returned as it is, because it depends on the source
+    /// text. [crate::format_source] resolves it with [LineEnding::resolve] before formatting.
     pub fn as_print_options(&self) -> PrinterOptions {
         PrinterOptions {
             indent_style: self.indent_style,
             indent_width: self.indent_width,
             line_ending: self.line_ending,
             print_width: self.line_width.into(),
+            ambiguous_width: self.ambiguous_width,
         }
     }
 
+    pub fn indent_style(&self) -> IndentStyle {
+        self.indent_style
+    }
+
+    pub fn indent_width(&self) -> IndentWidth {
+        self.indent_width
+    }
+
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    pub fn line_width(&self) -> LineWidth {
+        self.line_width
+    }
+
     pub fn arrow_parentheses(&self) -> ArrowParentheses {
         self.arrow_parentheses
     }
@@ -89,11 +264,32 @@ impl FormatOptions {
     pub fn expand(&self) -> Expand {
         self.expand
     }
+
+    pub fn ambiguous_width(&self) -> AmbiguousWidth {
+        self.ambiguous_width
+    }
+
+    pub fn require_pragma(&self) -> bool {
+        self.require_pragma
+    }
+
+    pub fn insert_pragma(&self) -> bool {
+        self.insert_pragma
+    }
+
+    pub fn print_ir(&self) -> bool {
+        self.print_ir
+    }
 }
 
 // ---
 
 #[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum IndentStyle {
     #[default]
     Tab,
@@ -113,7 +309,35 @@ impl IndentStyle {
     }
 }
 
+impl FromStr for IndentStyle {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tab" => Ok(Self::Tab),
+            "space" => Ok(Self::Space),
+            _ => {
+                Err("Value not supported for IndentStyle. Supported values are 'tab' and 'space'.")
+            }
+        }
+    }
+}
+
+impl fmt::Display for IndentStyle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IndentStyle::Tab => std::write!(f, "Tab"),
+            IndentStyle::Space => std::write!(f, "Space"),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u8", into = "u8")
+)]
 pub struct IndentWidth(u8);
 impl IndentWidth {
     pub const MIN: u8 = 0;
@@ -130,12 +354,56 @@ impl Default for IndentWidth {
         Self(2)
     }
 }
+impl From<IndentWidth> for u8 {
+    fn from(value: IndentWidth) -> Self {
+        value.0
+    }
+}
+impl TryFrom<u8> for IndentWidth {
+    type Error = &'static str;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        if (Self::MIN..=Self::MAX).contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err("The indent width should be between 0 and 24.")
+        }
+    }
+}
+impl FromStr for IndentWidth {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = u8::from_str(s).map_err(|_| "The indent width should be a number.")?;
+        Self::try_from(value)
+    }
+}
+impl fmt::Display for IndentWidth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        std::write!(f, "{}", self.0)
+    }
+}
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "u16", into = "u16")
+)]
 pub struct LineWidth(u16);
+impl LineWidth {
+    pub const MIN: u16 = 1;
+
+    pub const MAX: u16 = 320;
+
+    /// Return the numeric value for this [LineWidth]
+    pub fn value(&self) -> u16 {
+        self.0
+    }
+}
 impl Default for LineWidth {
     fn default() -> Self {
-        Self(8) // TODO: Revert to 80
+        Self(80)
     }
 }
 impl From<LineWidth> for u16 {
@@ -143,6 +411,30 @@ impl From<LineWidth> for u16 {
         value.0
     }
 }
+impl TryFrom<u16> for LineWidth {
+    type Error = &'static str;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        if (Self::MIN..=Self::MAX).contains(&value) {
+            Ok(Self(value))
+        } else {
+            Err("The line width should be between 1 and 320.")
+        }
+    }
+}
+impl FromStr for LineWidth {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = u16::from_str(s).map_err(|_| "The line width should be a number.")?;
+        Self::try_from(value)
+    }
+}
+impl fmt::Display for LineWidth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        std::write!(f, "{}", self.0)
+    }
+}
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct PrintWidth(u32);
@@ -171,6 +463,11 @@ impl From<PrintWidth> for usize {
 }
 
 #[derive(Debug, Copy, Clone, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum LineEnding {
     ///  Line Feed only (\n), common on Linux and macOS as well as inside git repos
     #[default]
@@ -179,6 +476,9 @@ pub enum LineEnding {
     Crlf,
     /// Carriage Return character only (\r), used very rarely
     Cr,
+    /// The line ending of the first line break of the source text, or [LineEnding::Lf] if
+    /// there is none
+    Auto,
 }
 impl LineEnding {
     #[inline]
@@ -187,6 +487,22 @@ impl LineEnding {
             LineEnding::Lf => "\n",
             LineEnding::Crlf => "\r\n",
             LineEnding::Cr => "\r",
+            // Resolved with [LineEnding::resolve] before printing
+            LineEnding::Auto => "\n",
+        }
+    }
+
+    /// Returns the line ending of the first line break of `source_text` if this is
+    /// [LineEnding::Auto], and `self` otherwise.
+    pub fn resolve(self, source_text: &str) -> Self {
+        if !self.is_auto() {
+            return self;
+        }
+
+        match source_text.find(['\r', '\n']) {
+            Some(index) if source_text[index..].starts_with("\r\n") => LineEnding::Crlf,
+            Some(index) if source_text[index..].starts_with('\r') => LineEnding::Cr,
+            _ => LineEnding::Lf,
         }
     }
 
@@ -204,9 +520,46 @@ impl LineEnding {
     pub const fn is_carriage_return(&self) -> bool {
         matches!(self, LineEnding::Cr)
     }
+
+    /// Returns `true` if this is a [LineEnding::Auto].
+    pub const fn is_auto(&self) -> bool {
+        matches!(self, LineEnding::Auto)
+    }
+}
+
+impl FromStr for LineEnding {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "lf" => Ok(Self::Lf),
+            "crlf" => Ok(Self::Crlf),
+            "cr" => Ok(Self::Cr),
+            "auto" => Ok(Self::Auto),
+            _ => Err(
+                "Value not supported for LineEnding. Supported values are 'lf', 'crlf', 'cr' and 'auto'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for LineEnding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LineEnding::Lf => std::write!(f, "LF"),
+            LineEnding::Crlf => std::write!(f, "CRLF"),
+            LineEnding::Cr => std::write!(f, "CR"),
+            LineEnding::Auto => std::write!(f, "Auto"),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum QuoteStyle {
     #[default]
     Double,
@@ -292,6 +645,11 @@ impl From<QuoteStyle> for Quote {
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct BracketSpacing(bool);
 
 impl BracketSpacing {
@@ -335,12 +693,23 @@ impl FromStr for BracketSpacing {
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum AttributePosition {
     #[default]
     Auto,
     Multiline,
 }
 
+impl AttributePosition {
+    pub const fn is_multiline(&self) -> bool {
+        matches!(self, Self::Multiline)
+    }
+}
+
 impl std::fmt::Display for AttributePosition {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -366,6 +735,11 @@ impl FromStr for AttributePosition {
 
 /// Put the `>` of a multi-line HTML or JSX element at the end of the last line instead of being alone on the next line (does not apply to self closing elements).
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
 pub struct BracketSameLine(bool);
 
 impl BracketSameLine {
@@ -401,6 +775,11 @@ impl FromStr for BracketSameLine {
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum Expand {
     /// Objects are expanded when the first property has a leading newline. Arrays are always
     /// expanded if they are shorter than the line width.
@@ -434,7 +813,125 @@ impl fmt::Display for Expand {
     }
 }
 
+/// The `objectWrap` option of Prettier, which sets [Expand] for objects.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum ObjectWrap {
+    /// Keep objects expanded if there's a line break between the `{` and the first property,
+    /// like [Expand::Auto].
+    #[default]
+    Preserve,
+    /// Print objects on a single line if they fit, like [Expand::Never].
+    Collapse,
+}
+
+impl From<ObjectWrap> for Expand {
+    fn from(object_wrap: ObjectWrap) -> Self {
+        match object_wrap {
+            ObjectWrap::Preserve => Expand::Auto,
+            ObjectWrap::Collapse => Expand::Never,
+        }
+    }
+}
+
+impl ObjectWrap {
+    /// Serializes [Expand] as the `objectWrap` option. [Expand::Always] has no equivalent and is
+    /// serialized as the `expand` option instead.
+    #[cfg(feature = "serde")]
+    fn serialize_expand<S: serde::Serializer>(
+        expand: &Expand,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(1))?;
+        match expand {
+            Expand::Auto => map.serialize_entry("objectWrap", &ObjectWrap::Preserve)?,
+            Expand::Never => map.serialize_entry("objectWrap", &ObjectWrap::Collapse)?,
+            Expand::Always => map.serialize_entry("expand", expand)?,
+        }
+        map.end()
+    }
+}
+
+impl FromStr for ObjectWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "collapse" => Ok(Self::Collapse),
+            _ => Err(
+                "Value not supported for ObjectWrap. Supported values are 'preserve' and 'collapse'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ObjectWrap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ObjectWrap::Preserve => std::write!(f, "Preserve"),
+            ObjectWrap::Collapse => std::write!(f, "Collapse"),
+        }
+    }
+}
+
+/// The width of the characters of the East Asian Width category "Ambiguous", like `°`, `±` or
+/// `→`. They are displayed in one column by most fonts, but in two columns by East Asian fonts.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum AmbiguousWidth {
+    /// One column
+    #[default]
+    Narrow,
+    /// Two columns
+    Wide,
+}
+
+impl AmbiguousWidth {
+    pub const fn is_wide(&self) -> bool {
+        matches!(self, Self::Wide)
+    }
+}
+
+impl FromStr for AmbiguousWidth {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "narrow" => Ok(Self::Narrow),
+            "wide" => Ok(Self::Wide),
+            _ => Err(
+                "Value not supported for AmbiguousWidth. Supported values are 'narrow' and 'wide'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for AmbiguousWidth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AmbiguousWidth::Narrow => std::write!(f, "Narrow"),
+            AmbiguousWidth::Wide => std::write!(f, "Wide"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum QuoteProperties {
     #[default]
     AsNeeded,
@@ -464,6 +961,11 @@ impl fmt::Display for QuoteProperties {
 }
 
 #[derive(Clone, Copy, Default, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 pub enum TrailingCommas {
     /// Trailing commas wherever possible (including function parameters and calls).
     #[default]
@@ -511,6 +1013,11 @@ impl fmt::Display for TrailingCommas {
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "kebab-case")
+)]
 pub enum Semicolons {
     #[default]
     Always,
@@ -551,9 +1058,13 @@ impl fmt::Display for Semicolons {
 }
 
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ArrowParentheses {
     #[default]
+    #[cfg_attr(feature = "serde", serde(rename = "always"))]
     Always,
+    /// `"avoid"` in Prettier.
+    #[cfg_attr(feature = "serde", serde(rename = "avoid"))]
     AsNeeded,
 }
 
@@ -590,3 +1101,136 @@ impl fmt::Display for ArrowParentheses {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for FormatOptions {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        crate::config::parse_options(&value)
+            .map(|config| config.apply(FormatOptions::default()))
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serializes options that Prettier sets with a boolean, e.g. `"semi": false`.
+#[cfg(feature = "serde")]
+mod prettier_bool {
+    use serde::Serializer;
+
+    use super::{AttributePosition, IndentStyle, QuoteStyle, Semicolons};
+
+    pub(super) trait PrettierBool: Copy {
+        fn to_bool(self) -> bool;
+    }
+
+    impl PrettierBool for IndentStyle {
+        fn to_bool(self) -> bool {
+            self.is_tab()
+        }
+    }
+
+    impl PrettierBool for QuoteStyle {
+        fn to_bool(self) -> bool {
+            !self.is_double()
+        }
+    }
+
+    impl PrettierBool for Semicolons {
+        fn to_bool(self) -> bool {
+            self.is_always()
+        }
+    }
+
+    impl PrettierBool for AttributePosition {
+        fn to_bool(self) -> bool {
+            self.is_multiline()
+        }
+    }
+
+    pub(super) fn serialize<T: PrettierBool, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bool(value.to_bool())
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::{
+        AmbiguousWidth, ArrowParentheses, AttributePosition, Expand, FormatOptions, IndentStyle,
+        LineWidth, QuoteStyle, Semicolons,
+    };
+
+    #[test]
+    fn prettier_json() {
+        let options = FormatOptions::from_json(
+            r#"{ "printWidth": 100, "useTabs": false, "semi": false, "singleQuote": true, "arrowParens": "avoid" }"#,
+        )
+        .unwrap();
+        assert_eq!(options.line_width(), LineWidth::try_from(100).unwrap());
+        assert_eq!(options.indent_style(), IndentStyle::Space);
+        assert_eq!(options.semicolons(), Semicolons::AsNeeded);
+        assert_eq!(options.quote_style(), QuoteStyle::Single);
+        assert_eq!(options.arrow_parentheses(), ArrowParentheses::AsNeeded);
+        assert_eq!(options.jsx_quote_style(), QuoteStyle::Double);
+
+        let json = serde_json::to_value(&options).unwrap();
+        assert_eq!(json["semi"], false);
+        assert_eq!(json["arrowParens"], "avoid");
+        assert_eq!(json["trailingComma"], "all");
+        assert_eq!(json["endOfLine"], "lf");
+
+        let options = FormatOptions::from_json(r#"{ "objectWrap": "collapse" }"#).unwrap();
+        assert_eq!(options.expand(), Expand::Never);
+        let json = serde_json::to_value(&options).unwrap();
+        assert_eq!(json["objectWrap"], "collapse");
+        assert!(json.get("expand").is_none());
+        assert!(FormatOptions::from_json(r#"{ "objectWrap": "always" }"#).is_err());
+        assert!(FormatOptions::from_json(r#"{ "expand": "collapse" }"#).is_err());
+
+        let options = FormatOptions::from_json(r#"{ "expand": "always" }"#).unwrap();
+        assert_eq!(options.expand(), Expand::Always);
+        let json = serde_json::to_value(&options).unwrap();
+        assert_eq!(json["expand"], "always");
+        assert!(json.get("objectWrap").is_none());
+
+        assert!(FormatOptions::from_json(r#"{ "printWidth": 0 }"#).is_err());
+        assert!(FormatOptions::from_json(r#"{ "semicolons": false }"#).is_err());
+    }
+
+    /// The options are read like a configuration file.
+    #[test]
+    fn prettier_json_like_config_file() {
+        let options = FormatOptions::from_json(
+            r#"{
+                "$schema": "http://json.schemastore.org/prettierrc",
+                "plugins": [],
+                "semi": false,
+                "overrides": [{ "files": "*.ts", "options": { "semi": true } }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(options.semicolons(), Semicolons::AsNeeded);
+
+        assert!(FormatOptions::from_json(r#"{ "arrowParens": "as-needed" }"#).is_err());
+
+        let options = FormatOptions::default()
+            .with_ambiguous_width(AmbiguousWidth::Wide)
+            .with_attribute_position(AttributePosition::Multiline)
+            .with_expand(Expand::Never);
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(
+            serde_json::to_value(FormatOptions::from_json(&json).unwrap()).unwrap(),
+            serde_json::to_value(&options).unwrap()
+        );
+
+        let options = FormatOptions::default().with_expand(Expand::Always);
+        let json = serde_json::to_string(&options).unwrap();
+        assert_eq!(
+            FormatOptions::from_json(&json).unwrap().expand(),
+            Expand::Always
+        );
+        assert!(FormatOptions::from_json(r#"{ "overrides": [{ "options": {} }] }"#).is_err());
+    }
+}
@@ -0,0 +1,200 @@
+//! Pragmas in the comments at the top of a file, e.g. `/** @format */`.
+
+use oxc_ast::Comment;
+use oxc_ast::ast::Program;
+use oxc_span::GetSpan;
+
+/// Pragma that excludes the whole file from formatting.
+pub const IGNORE_PRAGMA: &str = "@format-ignore";
+
+/// Pragmas that mark a file as formatted, see [crate::FormatOptions::require_pragma].
+pub const FORMAT_PRAGMAS: [&str; 2] = ["@format", "@prettier"];
+
+/// Returns `true` if one of the comments before the first directive or statement of the program
+/// contains `pragma` as a separate word.
+pub fn has_pragma(program: &Program, pragma: &str) -> bool {
+    let first_token = program
+        .directives
+        .first()
+        .map(GetSpan::span)
+        .or_else(|| program.body.first().map(GetSpan::span))
+        .map_or(program.source_text.len() as u32, |span| span.start);
+
+    program
+        .comments
+        .iter()
+        .take_while(|comment| comment.span.end <= first_token)
+        .any(|comment| comment_contains_pragma(program.source_text, comment, pragma))
+}
+
+/// Returns `true` if the docblock of the program contains `pragma` as a separate word.
+/// Pragmas in other comments, e.g. `// @format`, don't count.
+pub fn has_docblock_pragma(program: &Program, pragma: &str) -> bool {
+    find_docblock(program.source_text, &program.comments)
+        .is_some_and(|docblock| comment_contains_pragma(program.source_text, docblock, pragma))
+}
+
+/// Returns the docblock at the top of the file. Like Prettier, only the first comment of the file
+/// after the hashbang can be the docblock, and it must start with `/**`, e.g. `/**/` is not a
+/// docblock.
+pub fn find_docblock<'a>(source_text: &str, comments: &'a [Comment]) -> Option<&'a Comment> {
+    let end = if source_text.starts_with("#!") {
+        source_text
+            .find('\n')
+            .map_or(source_text.len(), |position| position + 1)
+    } else {
+        0
+    };
+
+    let comment = comments.first()?;
+    let start = comment.span.start as usize;
+    let is_first = source_text[end.min(start)..start]
+        .chars()
+        .all(char::is_whitespace);
+    let is_docblock = comment.is_block()
+        && source_text[start..].starts_with("/**")
+        && !source_text[start..].starts_with("/**/");
+
+    (is_first && is_docblock).then_some(comment)
+}
+
+fn comment_contains_pragma(source_text: &str, comment: &Comment, pragma: &str) -> bool {
+    let content = comment.content_span();
+    contains_pragma(
+        &source_text[content.start as usize..content.end as usize],
+        pragma,
+    )
+}
+
+/// Returns `true` if the comment content contains `pragma` as a separate word.
+pub fn contains_pragma(content: &str, pragma: &str) -> bool {
+    content
+        .split(|c: char| c.is_whitespace() || c == '*')
+        .any(|word| word == pragma)
+}
+
+/// Adds the `@format` pragma to a docblock, or creates a new docblock if there's none.
+///
+/// Like Prettier, the text of the docblock comes first and is followed by the pragmas, starting
+/// with `@format`:
+///
+/// ```js
+/// /**
+///  * Some description
+///  *
+///  * @format
+///  * @flow
+///  */
+/// ```
+pub fn insert_format_pragma(docblock: Option<&str>) -> String {
+    let content = docblock
+        .map(|docblock| {
+            docblock
+                .trim_start_matches("/**")
+                .trim_end_matches("*/")
+                .lines()
+                .map(|line| line.trim().trim_start_matches('*').trim())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let (pragmas, comments): (Vec<_>, Vec<_>) =
+        content.into_iter().partition(|line| line.starts_with('@'));
+
+    // Trim blank lines around the text, but keep the ones inside it
+    let first = comments.iter().position(|line| !line.is_empty());
+    let last = comments.iter().rposition(|line| !line.is_empty());
+    let comments = match (first, last) {
+        (Some(first), Some(last)) => &comments[first..=last],
+        _ => &[],
+    };
+
+    if comments.is_empty() && pragmas.is_empty() {
+        return String::from("/** @format */");
+    }
+
+    let mut docblock = String::from("/**\n");
+    for line in comments {
+        push_docblock_line(&mut docblock, line);
+    }
+    if !comments.is_empty() {
+        docblock.push_str(" *\n");
+    }
+    push_docblock_line(&mut docblock, "@format");
+    for pragma in pragmas {
+        push_docblock_line(&mut docblock, pragma);
+    }
+    docblock.push_str(" */");
+
+    docblock
+}
+
+fn push_docblock_line(docblock: &mut String, line: &str) {
+    if line.is_empty() {
+        docblock.push_str(" *\n");
+    } else {
+        docblock.push_str(" * ");
+        docblock.push_str(line);
+        docblock.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use oxc_allocator::Allocator;
+    use oxc_parser::Parser;
+    use oxc_span::SourceType;
+
+    use crate::pragma::{IGNORE_PRAGMA, has_docblock_pragma, has_pragma, insert_format_pragma};
+
+    fn has_format_pragma(source_text: &str) -> bool {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        has_docblock_pragma(&ret.program, "@format")
+    }
+
+    fn has_ignore_pragma(source_text: &str) -> bool {
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+        has_pragma(&ret.program, IGNORE_PRAGMA)
+    }
+
+    #[test]
+    fn test_has_docblock_pragma() {
+        assert!(has_format_pragma("/** @format */\nfoo();"));
+        assert!(has_format_pragma(
+            "#!/usr/bin/env node\n/** @format */\nfoo();"
+        ));
+        assert!(!has_format_pragma("// @format\nfoo();"));
+        assert!(!has_format_pragma("/* @format */\nfoo();"));
+        assert!(!has_format_pragma("/**/ /** @format */\nfoo();"));
+        assert!(!has_format_pragma("// license\n/** @format */\nfoo();"));
+        assert!(!has_format_pragma("/** doc */\n/** @format */\nfoo();"));
+        assert!(!has_format_pragma("foo();\n/** @format */"));
+    }
+
+    #[test]
+    fn test_has_pragma() {
+        assert!(has_ignore_pragma("// @format-ignore\nfoo();"));
+        assert!(has_ignore_pragma("/** @format-ignore */\nfoo();"));
+        assert!(has_ignore_pragma(
+            "// license\n/* @format-ignore */\nfoo();"
+        ));
+        assert!(!has_ignore_pragma("// @format-ignored\nfoo();"));
+        assert!(!has_ignore_pragma("foo();\n// @format-ignore"));
+    }
+
+    #[test]
+    fn test_insert_format_pragma() {
+        assert_eq!(insert_format_pragma(None), "/** @format */");
+        assert_eq!(insert_format_pragma(Some("/** */")), "/** @format */");
+        assert_eq!(
+            insert_format_pragma(Some("/** @flow */")),
+            "/**\n * @format\n * @flow\n */"
+        );
+        assert_eq!(
+            insert_format_pragma(Some("/**\n * Hello\n *\n * world\n */")),
+            "/**\n * Hello\n *\n * world\n *\n * @format\n */"
+        );
+    }
+}
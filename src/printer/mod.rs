@@ -3,11 +3,11 @@ mod line_suffixes;
 mod options;
 mod queue;
 mod stack;
+mod width;
 
 use std::num::NonZeroU8;
 
 use drop_bomb::DebugDropBomb;
-use unicode_width::UnicodeWidthChar;
 
 pub use options::*;
 
@@ -27,6 +27,7 @@ use crate::printer::queue::{
 };
 
 use self::call_stack::PrintIndentStack;
+use self::width::text_width;
 
 /// Prints the format elements into a string
 #[derive(Debug, Default)]
@@ -639,9 +640,17 @@ impl<'a> Printer<'a> {
     }
 
     fn print_str(&mut self, content: &str) {
-        for char in content.chars() {
-            self.print_char(char);
+        for (index, line) in content.split('\n').enumerate() {
+            if index > 0 {
+                self.print_char('\n');
+            }
+
+            self.state.buffer.push_str(line);
+            self.state.generated_column += line.chars().count();
+            self.state.line_width += text_width(line, &self.options);
+        }
 
+        if !content.is_empty() {
             self.state.has_empty_line = false;
         }
     }
@@ -662,14 +671,7 @@ impl<'a> Printer<'a> {
         } else {
             self.state.buffer.push(char);
             self.state.generated_column += 1;
-
-            let char_width = if char == '\t' {
-                self.options.indent_width().value() as usize
-            } else {
-                char.width().unwrap_or(0)
-            };
-
-            self.state.line_width += char_width;
+            self.state.line_width += text_width(char.encode_utf8(&mut [0; 4]), &self.options);
         }
     }
 }
@@ -1212,22 +1214,20 @@ impl<'a, 'print> FitsMeasurer<'a, 'print> {
             self.state.line_width += 1;
         }
 
-        for c in text.chars() {
-            let char_width = match c {
-                '\t' => self.options().indent_width.value() as usize,
-                '\n' => {
-                    return if self.must_be_flat
-                        || self.state.line_width
-                            > <PrintWidth as Into<usize>>::into(self.options().print_width)
-                    {
-                        Fits::No
-                    } else {
-                        Fits::Yes
-                    };
-                }
-                c => c.width().unwrap_or(0),
+        // Only the text up to the first line break is on the current line
+        let line_end = text.find('\n');
+        let line = &text[..line_end.unwrap_or(text.len())];
+        self.state.line_width += text_width(line, self.options());
+
+        if line_end.is_some() {
+            return if self.must_be_flat
+                || self.state.line_width
+                    > <PrintWidth as Into<usize>>::into(self.options().print_width)
+            {
+                Fits::No
+            } else {
+                Fits::Yes
             };
-            self.state.line_width += char_width;
         }
 
         if self.state.line_width > <PrintWidth as Into<usize>>::into(self.options().print_width) {
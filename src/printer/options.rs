@@ -1,4 +1,4 @@
-use crate::options::{IndentStyle, IndentWidth, LineEnding, LineWidth};
+use crate::options::{AmbiguousWidth, IndentStyle, IndentWidth, LineEnding, LineWidth};
 
 /// Options that affect how the [crate::Printer] prints the format tokens
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -15,6 +15,9 @@ pub struct PrinterOptions {
 
     /// Whether the printer should use tabs or spaces to indent code and if spaces, by how many.
     pub indent_style: IndentStyle,
+
+    /// The width of the characters whose width is ambiguous in East Asian typography
+    pub ambiguous_width: AmbiguousWidth,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -67,6 +70,12 @@ impl PrinterOptions {
         self
     }
 
+    pub fn with_ambiguous_width(mut self, ambiguous_width: AmbiguousWidth) -> Self {
+        self.ambiguous_width = ambiguous_width;
+
+        self
+    }
+
     pub(crate) fn indent_style(&self) -> IndentStyle {
         self.indent_style
     }
@@ -89,6 +98,7 @@ impl Default for PrinterOptions {
             print_width: PrintWidth::default(),
             indent_style: Default::default(),
             line_ending: LineEnding::Lf,
+            ambiguous_width: AmbiguousWidth::default(),
         }
     }
 }
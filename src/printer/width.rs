@@ -0,0 +1,104 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthChar;
+
+use crate::options::AmbiguousWidth;
+use crate::printer::PrinterOptions;
+
+const VARIATION_SELECTOR_16: char = '\u{FE0F}';
+
+/// Returns the number of columns that `text` takes when displayed. `text` must not contain
+/// line breaks.
+///
+/// The width is measured per grapheme cluster rather than per character, so that emoji
+/// sequences, variation selectors and combining marks are measured as they are displayed.
+pub(super) fn text_width(text: &str, options: &PrinterOptions) -> usize {
+    let tab_width = options.indent_width().value() as usize;
+
+    if text.is_ascii() {
+        return text
+            .bytes()
+            .map(|byte| match byte {
+                b'\t' => tab_width,
+                b' '..=b'~' => 1,
+                // Control characters
+                _ => 0,
+            })
+            .sum();
+    }
+
+    text.graphemes(true)
+        .map(|grapheme| grapheme_width(grapheme, tab_width, options.ambiguous_width))
+        .sum()
+}
+
+fn grapheme_width(grapheme: &str, tab_width: usize, ambiguous_width: AmbiguousWidth) -> usize {
+    let mut chars = grapheme.chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+    if first == '\t' {
+        return tab_width;
+    }
+
+    let first_width = char_width(first, ambiguous_width);
+    if chars.as_str().is_empty() {
+        return first_width;
+    }
+
+    // A wide character followed by combining marks, Hangul vowels, emoji modifiers or the rest of
+    // an emoji ZWJ sequence is displayed as one wide character. So are emoji presentation
+    // sequences (`❤️`, `1️⃣`) and flags, which start with a narrow character.
+    if first_width == 2 || grapheme.contains(VARIATION_SELECTOR_16) || is_regional_indicator(first)
+    {
+        return 2;
+    }
+
+    first_width
+        + chars
+            .map(|char| char_width(char, ambiguous_width))
+            .sum::<usize>()
+}
+
+fn char_width(char: char, ambiguous_width: AmbiguousWidth) -> usize {
+    let width = if ambiguous_width.is_wide() {
+        char.width_cjk()
+    } else {
+        char.width()
+    };
+    width.unwrap_or(0)
+}
+
+fn is_regional_indicator(char: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&char)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::text_width;
+    use crate::options::AmbiguousWidth;
+    use crate::printer::PrinterOptions;
+
+    #[test]
+    fn grapheme_widths() {
+        let options = PrinterOptions::default();
+        let width = |text: &str| text_width(text, &options);
+
+        assert_eq!(width("let a = 1;"), 10);
+        assert_eq!(width("\ta"), 3);
+        assert_eq!(width("日本語のテキスト"), 16);
+        assert_eq!(width("ｶﾀｶﾅ"), 4);
+        // Combining acute accent
+        assert_eq!(width("cafe\u{301}"), 4);
+        // Emoji with a skin tone, a ZWJ family, a flag, an emoji presentation and a keycap
+        assert_eq!(width("👍🏽"), 2);
+        assert_eq!(width("👨‍👩‍👧"), 2);
+        assert_eq!(width("🇯🇵"), 2);
+        assert_eq!(width("❤️"), 2);
+        assert_eq!(width("1️⃣"), 2);
+        assert_eq!(width("「こんにちは👋」"), 16);
+
+        assert_eq!(width("±→"), 2);
+        let options = options.with_ambiguous_width(AmbiguousWidth::Wide);
+        assert_eq!(text_width("±→", &options), 4);
+    }
+}
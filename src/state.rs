@@ -1,29 +1,29 @@
 use crate::context::FormatContext;
 use crate::group_id::{GroupId, UniqueGroupIdBuilder};
 
-pub struct FormatState {
-    context: FormatContext,
+pub struct FormatState<'ast> {
+    context: FormatContext<'ast>,
     group_id_builder: UniqueGroupIdBuilder,
 }
-impl FormatState {
-    pub fn new(context: FormatContext) -> Self {
+impl<'ast> FormatState<'ast> {
+    pub fn new(context: FormatContext<'ast>) -> Self {
         Self {
             context,
             group_id_builder: UniqueGroupIdBuilder::default(),
         }
     }
 
-    pub fn into_context(self) -> FormatContext {
+    pub fn into_context(self) -> FormatContext<'ast> {
         self.context
     }
 
     /// Returns the context specifying how to format the current CST
-    pub fn context(&self) -> &FormatContext {
+    pub fn context(&self) -> &FormatContext<'ast> {
         &self.context
     }
 
     /// Returns a mutable reference to the context
-    pub fn context_mut(&mut self) -> &mut FormatContext {
+    pub fn context_mut(&mut self) -> &mut FormatContext<'ast> {
         &mut self.context
     }
 
@@ -35,7 +35,7 @@ impl FormatState {
     }
 }
 
-impl std::fmt::Debug for FormatState {
+impl std::fmt::Debug for FormatState<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         f.debug_struct("FormatState")
             .field("context", &self.context)
@@ -0,0 +1,112 @@
+//! Tests of the `oxc_formatter` binary over temporary directories: the exit codes and the summary
+//! printed to stderr.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A directory in the temporary directory of the system, removed when dropped.
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let path =
+            std::env::temp_dir().join(format!("oxc_formatter-cli-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&path);
+        fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    fn write(&self, path: &str, content: &str) -> PathBuf {
+        let path = self.0.join(path);
+        fs::write(&path, content).unwrap();
+        path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
+
+/// Runs the binary in `dir` and returns its exit code, stdout and stderr.
+fn run(args: &[&str], dir: &Path) -> (Option<i32>, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_oxc_formatter"))
+        .arg("--no-config")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+
+    (
+        output.status.code(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+#[test]
+fn check() {
+    let dir = TempDir::new("check");
+    dir.write("formatted.js", "foo();\n");
+    let unformatted = dir.write("unformatted.js", "foo( )\n");
+
+    let (code, stdout, stderr) = run(&["--check", "."], &dir.0);
+    assert_eq!(code, Some(1));
+    assert_eq!(stdout, "./unformatted.js\n");
+    assert!(stderr.starts_with("Checked 2 files in "), "{stderr}");
+    assert!(
+        stderr.ends_with(": 1 unformatted, 1 formatted, 0 failed\n"),
+        "{stderr}"
+    );
+
+    fs::write(&unformatted, "foo();\n").unwrap();
+    let (code, _, stderr) = run(&["--check", "."], &dir.0);
+    assert_eq!(code, Some(0));
+    assert!(stderr.ends_with(": 0 unformatted, 2 formatted, 0 failed\n"));
+}
+
+#[test]
+fn write() {
+    let dir = TempDir::new("write");
+    dir.write("formatted.js", "foo();\n");
+    let unformatted = dir.write("unformatted.js", "foo( )\n");
+
+    let (code, stdout, stderr) = run(&["--write", "."], &dir.0);
+    assert_eq!(code, Some(0));
+    assert_eq!(stdout, "./unformatted.js\n");
+    assert!(stderr.starts_with("Formatted 2 files in "), "{stderr}");
+    assert!(
+        stderr.ends_with(": 1 changed, 1 unchanged, 0 failed\n"),
+        "{stderr}"
+    );
+    assert_eq!(fs::read_to_string(unformatted).unwrap(), "foo();\n");
+}
+
+#[test]
+fn errors() {
+    let dir = TempDir::new("errors");
+    dir.write("formatted.js", "foo();\n");
+    dir.write("unformatted.js", "foo( )\n");
+    dir.write("invalid.js", "foo(\n");
+
+    let (code, _, stderr) = run(&["--check", "."], &dir.0);
+    assert_eq!(code, Some(2));
+    assert!(stderr.starts_with("./invalid.js: "), "{stderr}");
+    assert!(
+        stderr.ends_with(": 1 unformatted, 1 formatted, 1 failed\n"),
+        "{stderr}"
+    );
+
+    let (code, _, stderr) = run(&["--write", "missing.js"], &dir.0);
+    assert_eq!(code, Some(2));
+    assert!(stderr.starts_with("missing.js: "), "{stderr}");
+    assert!(
+        stderr.ends_with(": 0 changed, 0 unchanged, 1 failed\n"),
+        "{stderr}"
+    );
+
+    let (code, _, _) = run(&["--unknown"], &dir.0);
+    assert_eq!(code, Some(2));
+}
@@ -0,0 +1,60 @@
+use crate::assert_format;
+
+#[test]
+fn class_decorators() {
+    assert_format(
+        "a.ts",
+        r#"@a @b class X {}
+const Foo = @dec class {};
+foo(@dec class {});
+"#,
+        r#"@a
+@b
+class X {}
+const Foo =
+	@dec
+	class {};
+foo(
+	@dec
+	class {},
+);
+"#,
+    );
+}
+
+#[test]
+fn decorators_before_and_after_export() {
+    assert_format(
+        "a.ts",
+        r#"export @dec class Y {}
+export default @a @b() class {}
+export @dec
+class W {}
+@dec export class Z {}
+"#,
+        r#"export @dec class Y {}
+export default @a @b() class {}
+export @dec
+class W {}
+@dec
+export class Z {}
+"#,
+    );
+}
+
+#[test]
+fn member_and_parameter_decorators() {
+    assert_format(
+        "a.ts",
+        r#"class M { @Input() name: string; @HostListener("click")
+ onClick() {} method(@Inject() s: S) {} }
+"#,
+        r#"class M {
+	@Input() name: string;
+	@HostListener("click")
+	onClick() {}
+	method(@Inject() s: S) {}
+}
+"#,
+    );
+}
@@ -0,0 +1,52 @@
+use oxc_formatter::{FormatOptions, QuoteStyle};
+
+use crate::{assert_format, assert_format_with_options};
+
+#[test]
+fn directives_keep_their_content() {
+    assert_format(
+        "a.js",
+        r#"#!/usr/bin/env node
+"use strict";
+'use client';
+"it's";
+'say "hi"';
+"a\u0041";
+foo();
+"#,
+        r#"#!/usr/bin/env node
+"use strict";
+"use client";
+"it's";
+'say "hi"';
+"a\u0041";
+foo();
+"#,
+    );
+}
+
+#[test]
+fn directive_quotes() {
+    assert_format_with_options(
+        "a.js",
+        r#""use strict"
+function f() { "use asm"; return 1 }
+"#,
+        r#"'use strict';
+function f() {
+	'use asm';
+	return 1;
+}
+"#,
+        FormatOptions::default().with_quote_style(QuoteStyle::Single),
+    );
+}
+
+#[test]
+fn hashbang() {
+    assert_format(
+        "a.js",
+        "#!/usr/bin/env node\nfoo()\n",
+        "#!/usr/bin/env node\nfoo();\n",
+    );
+}
@@ -0,0 +1,25 @@
+use crate::assert_format;
+
+#[test]
+fn binary_chains() {
+    assert_format(
+        "a.js",
+        r#"const x = aaaaaaaaaaaaaaaaaaaa * bbbbbbbbbbbbbbbbbbbbbbbbb * cccccccccccccccccccccccc * ddddddddddddd;
+const y = aaaaaaaaaaaaaaaaaaaa % bbbbbbbbbbbbbbbbbbbbbbbbb % cccccccccccccccccccccccc % ddddddddddddd;
+foo(aaaaaaaaaaaaaaaaaaaa << bbbbbbbbbbbbbbbbbbbbbbbbb << cccccccccccccccccccccccc << dddddddd);
+"#,
+        r#"const x = aaaaaaaaaaaaaaaaaaaa *
+	bbbbbbbbbbbbbbbbbbbbbbbbb *
+	cccccccccccccccccccccccc *
+	ddddddddddddd;
+const y = aaaaaaaaaaaaaaaaaaaa % bbbbbbbbbbbbbbbbbbbbbbbbb %
+	cccccccccccccccccccccccc %
+	ddddddddddddd;
+foo(
+	aaaaaaaaaaaaaaaaaaaa << bbbbbbbbbbbbbbbbbbbbbbbbb <<
+		cccccccccccccccccccccccc <<
+		dddddddd,
+);
+"#,
+    );
+}
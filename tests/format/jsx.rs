@@ -0,0 +1,122 @@
+use oxc_formatter::{AttributePosition, BracketSameLine, FormatOptions, QuoteStyle};
+
+use crate::{assert_format, assert_format_with_options};
+
+#[test]
+fn elements_and_fragments() {
+    assert_format(
+        "a.jsx",
+        r#"const a = <div className="x" id='y'>hi</div>;
+const b = <Foo {...props} disabled />;
+const c = <><A /><B /></>;
+const e = <a.b.c x={1} />;
+"#,
+        r#"const a = (
+	<div className="x" id="y">
+		hi
+	</div>
+);
+const b = <Foo {...props} disabled />;
+const c = (
+	<>
+		<A />
+		<B />
+	</>
+);
+const e = <a.b.c x={1} />;
+"#,
+    );
+}
+
+#[test]
+fn multiline_jsx_in_return_and_arrow_bodies() {
+    assert_format(
+        "a.jsx",
+        r#"function App() { return <div className="aaaaaaaaaaaaaaaaaaaa" title="bbbbbbbbbbbbbbbbbbbbbbb" onClick={handleClick}>text</div>; }
+const d = () => <section><header>Title of the section</header><p>Some paragraph text</p></section>;
+"#,
+        r#"function App() {
+	return (
+		<div
+			className="aaaaaaaaaaaaaaaaaaaa"
+			title="bbbbbbbbbbbbbbbbbbbbbbb"
+			onClick={handleClick}
+		>
+			text
+		</div>
+	);
+}
+const d = () => (
+	<section>
+		<header>Title of the section</header>
+		<p>Some paragraph text</p>
+	</section>
+);
+"#,
+    );
+}
+
+#[test]
+fn attribute_options() {
+    let options = FormatOptions::default()
+        .with_bracket_same_line(BracketSameLine::from(true))
+        .with_jsx_quote_style(QuoteStyle::Single)
+        .with_attribute_position(AttributePosition::Multiline);
+    assert_format_with_options(
+        "a.jsx",
+        r#"function App() { return <div className="aaaaaaaaaaaaaaaaaaaa" title="bbbbbbbbbbbbbbbbbbbbbbb" onClick={handleClick}>text</div>; }
+const b = <Foo a="1" b='it"s' />;
+const c = <Foo a="1" />;
+"#,
+        r#"function App() {
+	return (
+		<div
+			className='aaaaaaaaaaaaaaaaaaaa'
+			title='bbbbbbbbbbbbbbbbbbbbbbb'
+			onClick={handleClick}>
+			text
+		</div>
+	);
+}
+const b = (
+	<Foo
+		a='1'
+		b='it"s'
+	/>
+);
+const c = <Foo a='1' />;
+"#,
+        options,
+    );
+}
+
+#[test]
+fn children_fill_and_whitespace() {
+    assert_format(
+        "a.jsx",
+        r#"const a = <p>Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore</p>;
+const b = <div>Hello <b>world</b> and {name}!</div>;
+const c = <div>{cond && <Spinner />}</div>;
+const e = <div>Some text that is long enough to wrap around <a href="https://example.com">a link</a> in it</div>;
+"#,
+        r#"const a = (
+	<p>
+		Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod
+		tempor incididunt ut labore
+	</p>
+);
+const b = (
+	<div>
+		Hello <b>world</b> and {name}!
+	</div>
+);
+const c = <div>{cond && <Spinner />}</div>;
+const e = (
+	<div>
+		Some text that is long enough to wrap around{" "}
+		<a href="https://example.com">a link</a> in it
+	</div>
+);
+"#,
+    );
+}
@@ -0,0 +1,29 @@
+use oxc_formatter::{FormatOptions, LineEnding};
+
+use crate::{assert_format, format_with_options};
+
+#[test]
+fn auto_line_ending() {
+    let options = FormatOptions::default().with_line_ending(LineEnding::Auto);
+    assert_eq!(
+        format_with_options(
+            "a.js",
+            "const a = `x\r\ny`;\r\nfoo(  1 );\r\n",
+            options.clone()
+        ),
+        "const a = `x\r\ny`;\r\nfoo(1);\r\n"
+    );
+    assert_eq!(
+        format_with_options("a.js", "foo(  1 );\nconst a = `x\r\ny`;\r\n", options),
+        "foo(1);\nconst a = `x\ny`;\n"
+    );
+}
+
+#[test]
+fn comments_keep_unicode_line_separators() {
+    assert_format(
+        "a.js",
+        "/** doc\u{2028}x */\nfunction f() { /* q\u{2028}r */ }\n",
+        "/** doc\u{2028}x */\nfunction f() {\n\t/* q\u{2028}r */\n}\n",
+    );
+}
@@ -0,0 +1,69 @@
+use oxc_formatter::{FormatOptions, Semicolons};
+
+use crate::{assert_format, assert_format_with_options};
+
+#[test]
+fn regular_expressions() {
+    assert_format(
+        "a.js",
+        r#"const r = /ab+c/gmi;
+const v = /[\p{L}--[a-z]]/v;
+x = a / /re/.source;
+"#,
+        r#"const r = /ab+c/gim;
+const v = /[\p{L}--[a-z]]/v;
+x = a / /re/.source;
+"#,
+    );
+}
+
+#[test]
+fn big_ints() {
+    assert_format(
+        "a.js",
+        "const b = 0XABn;\nconst d = 0B101n;\nconst e = 10n;\n",
+        "const b = 0xabn;\nconst d = 0b101n;\nconst e = 10n;\n",
+    );
+}
+
+#[test]
+fn regular_expression_starting_a_statement_without_semicolons() {
+    assert_format_with_options(
+        "a.js",
+        "a = b;\n/re/.test(c);\nfoo();\n",
+        "a = b\n;/re/.test(c)\nfoo()\n",
+        FormatOptions::default().with_semicolons(Semicolons::AsNeeded),
+    );
+}
+
+#[test]
+fn string_escapes_are_kept() {
+    assert_format(
+        "a.js",
+        r#"const a = 'say "hi"';
+const b = 'a\nb\tc';
+const c = 'it\'s';
+const d = 'é\x41\u{1F600}';
+const e = "é ☃";
+"#,
+        r#"const a = 'say "hi"';
+const b = "a\nb\tc";
+const c = "it\'s";
+const d = "é\x41\u{1F600}";
+const e = "é ☃";
+"#,
+    );
+}
+
+#[test]
+fn strings_in_arguments_and_properties() {
+    assert_format(
+        "a.js",
+        r#"foo('say "hi"', 'a\nb', 'é');
+const o = { 'key"q': 'v"w', b: 'x\ty' };
+"#,
+        r#"foo('say "hi"', "a\nb", "é");
+const o = { 'key"q': 'v"w', b: "x\ty" };
+"#,
+    );
+}
@@ -0,0 +1,44 @@
+//! Tests of the formatted output of `format_source`, grouped by the kind of syntax.
+
+mod decorators;
+mod directives;
+mod expressions;
+mod jsx;
+mod line_endings;
+mod literals;
+mod modules;
+mod object_wrap;
+mod pragmas;
+mod statements;
+mod suppression;
+mod typescript;
+
+use oxc_formatter::{FormatOptions, format_source};
+use oxc_span::SourceType;
+
+/// Formats `source` as the file `path` with the default options.
+fn format(path: &str, source: &str) -> String {
+    format_with_options(path, source, FormatOptions::default())
+}
+
+/// Formats `source` as the file `path`, which only sets the [SourceType].
+fn format_with_options(path: &str, source: &str, options: FormatOptions) -> String {
+    let source_type = SourceType::from_path(path).unwrap();
+    format_source(source, source_type, options).unwrap()
+}
+
+/// Asserts that `source` formats to `expected`, and that `expected` is stable.
+#[track_caller]
+fn assert_format(path: &str, source: &str, expected: &str) {
+    assert_format_with_options(path, source, expected, FormatOptions::default());
+}
+
+#[track_caller]
+fn assert_format_with_options(path: &str, source: &str, expected: &str, options: FormatOptions) {
+    assert_eq!(format_with_options(path, source, options.clone()), expected);
+    assert_eq!(
+        format_with_options(path, expected, options),
+        expected,
+        "The formatted output isn't stable"
+    );
+}
@@ -0,0 +1,130 @@
+use oxc_formatter::{BracketSpacing, FormatOptions, LineWidth, QuoteStyle, TrailingCommas};
+
+use crate::{assert_format, assert_format_with_options};
+
+#[test]
+fn imports() {
+    assert_format(
+        "a.js",
+        r#"import a from "a";
+import * as ns from "ns";
+import b,{c,d as e} from "b";
+import def, * as all from "all";
+import {} from "empty";
+import "side-effect";
+import json from "./data.json" with { type: "json" };
+import { aaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbb, cccccccccccccccccccc, dddddddddddd } from "long";
+"#,
+        r#"import a from "a";
+import * as ns from "ns";
+import b, { c, d as e } from "b";
+import def, * as all from "all";
+import {} from "empty";
+import "side-effect";
+import json from "./data.json" with { type: "json" };
+import {
+	aaaaaaaaaaaaaaaa,
+	bbbbbbbbbbbbbbbbbbbb,
+	cccccccccccccccccccc,
+	dddddddddddd,
+} from "long";
+"#,
+    );
+}
+
+#[test]
+fn exports() {
+    assert_format(
+        "a.js",
+        r#"export {a,b as c};
+export * from "all";
+export * as ns from "ns";
+export { y } from "y";
+export const x = 1;
+export default function() {}
+"#,
+        r#"export { a, b as c };
+export * from "all";
+export * as ns from "ns";
+export { y } from "y";
+export const x = 1;
+export default function () {}
+"#,
+    );
+}
+
+#[test]
+fn type_imports_and_exports() {
+    assert_format(
+        "a.ts",
+        r#"import type {T} from "t";
+import { type U, V } from "u";
+export type { T };
+"#,
+        r#"import type { T } from "t";
+import { type U, V } from "u";
+export type { T };
+"#,
+    );
+}
+
+#[test]
+fn specifier_options() {
+    let options = FormatOptions::default()
+        .with_line_width(LineWidth::try_from(40).unwrap())
+        .with_bracket_spacing(BracketSpacing::from(false))
+        .with_trailing_commas(TrailingCommas::None);
+    assert_format_with_options(
+        "a.js",
+        r#"import { aaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbb } from "long";
+export { a, b as c };
+"#,
+        r#"import {
+	aaaaaaaaaaaaaaaa,
+	bbbbbbbbbbbbbbbbbbbb
+} from "long";
+export {a, b as c};
+"#,
+        options,
+    );
+}
+
+#[test]
+fn single_specifier_does_not_break() {
+    assert_format(
+        "a.js",
+        r#"import { aVeryLongSpecifierNameThatDoesNotFitOnTheLineAtAllBecauseItIsLong } from "a-module";
+"#,
+        r#"import { aVeryLongSpecifierNameThatDoesNotFitOnTheLineAtAllBecauseItIsLong } from "a-module";
+"#,
+    );
+}
+
+#[test]
+fn string_sources_and_names() {
+    assert_format(
+        "a.js",
+        r#"import x from 'mod';
+import y from 'it\'s';
+import z from "say \"hi\"";
+export { "a b" as z, 'c"d' as w } from './mA';
+import j from "./a.json" with { type: 'json' };
+"#,
+        r#"import x from "mod";
+import y from "it\'s";
+import z from "say \"hi\"";
+export { "a b" as z, 'c"d' as w } from "./mA";
+import j from "./a.json" with { type: "json" };
+"#,
+    );
+    assert_format_with_options(
+        "a.js",
+        r#"import x from "mod";
+import y from "it's";
+"#,
+        r#"import x from 'mod';
+import y from "it's";
+"#,
+        FormatOptions::default().with_quote_style(QuoteStyle::Single),
+    );
+}
@@ -0,0 +1,97 @@
+use oxc_formatter::{Expand, FormatOptions, ObjectWrap};
+
+use crate::assert_format_with_options;
+
+const SOURCE: &str = r#"interface A { a: string }
+interface B {
+  a: string }
+type T = { a: string };
+type U = {
+  a: string };
+const o = { a: 1 };
+const p = {
+  a: 1 };
+import { a,
+  b } from "x";
+import {
+  c, d } from "y";
+import {
+  q } from "q";
+export { e };
+"#;
+
+#[test]
+fn preserve() {
+    assert_format_with_options(
+        "a.ts",
+        SOURCE,
+        r#"interface A {
+	a: string;
+}
+interface B {
+	a: string;
+}
+type T = { a: string };
+type U = {
+	a: string;
+};
+const o = { a: 1 };
+const p = {
+	a: 1,
+};
+import { a, b } from "x";
+import {
+	c,
+	d,
+} from "y";
+import {
+	q,
+} from "q";
+export { e };
+"#,
+        FormatOptions::default().with_expand(Expand::from(ObjectWrap::Preserve)),
+    );
+}
+
+#[test]
+fn collapse() {
+    assert_format_with_options(
+        "a.ts",
+        SOURCE,
+        r#"interface A { a: string }
+interface B { a: string }
+type T = { a: string };
+type U = { a: string };
+const o = { a: 1 };
+const p = { a: 1 };
+import { a, b } from "x";
+import { c, d } from "y";
+import { q } from "q";
+export { e };
+"#,
+        FormatOptions::default().with_expand(Expand::from(ObjectWrap::Collapse)),
+    );
+}
+
+#[test]
+fn always() {
+    assert_format_with_options(
+        "a.ts",
+        "type T = { a: string };\nconst o = { a: 1 };\nimport { a, b } from \"x\";\nexport { e };\n",
+        r#"type T = {
+	a: string;
+};
+const o = {
+	a: 1,
+};
+import {
+	a,
+	b,
+} from "x";
+export {
+	e,
+};
+"#,
+        FormatOptions::default().with_expand(Expand::Always),
+    );
+}
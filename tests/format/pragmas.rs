@@ -0,0 +1,96 @@
+use oxc_formatter::FormatOptions;
+
+use crate::{assert_format, assert_format_with_options, format_with_options};
+
+#[test]
+fn comments_at_the_top_of_the_file() {
+    assert_format(
+        "a.js",
+        "#!/usr/bin/env node\n/* a */ // b\n\n/** doc */\n\nconst a=1\n",
+        "#!/usr/bin/env node\n/* a */ // b\n\n/** doc */\n\nconst a = 1;\n",
+    );
+    assert_format(
+        "a.js",
+        "// license\nconst a=1\n",
+        "// license\nconst a = 1;\n",
+    );
+}
+
+#[test]
+fn insert_pragma() {
+    let options = FormatOptions::default().with_insert_pragma(true);
+    assert_format_with_options(
+        "a.js",
+        "/** doc */\n// license\nconst a=1\n",
+        r#"/**
+ * doc
+ *
+ * @format
+ */
+
+// license
+const a = 1;
+"#,
+        options.clone(),
+    );
+    assert_format_with_options(
+        "a.js",
+        "/** @format */\nconst a=1\n",
+        "/** @format */\nconst a = 1;\n",
+        options.clone(),
+    );
+    assert_format_with_options(
+        "a.js",
+        "const a=1\n",
+        "/** @format */\n\nconst a = 1;\n",
+        options,
+    );
+}
+
+#[test]
+fn insert_pragma_before_other_comments() {
+    let options = FormatOptions::default().with_insert_pragma(true);
+    assert_format_with_options(
+        "a.js",
+        "// license\n/** @format */\nconst a=1\n",
+        "/** @format */\n\n// license\n/** @format */\nconst a = 1;\n",
+        options.clone(),
+    );
+    assert_format_with_options(
+        "a.js",
+        "#!/usr/bin/env node\n// license\n\nconst a=1\n",
+        "#!/usr/bin/env node\n/** @format */\n\n// license\n\nconst a = 1;\n",
+        options.clone(),
+    );
+    assert_format_with_options(
+        "a.js",
+        "/**/\nconst a=1\n",
+        "/** @format */\n\n/**/\nconst a = 1;\n",
+        options,
+    );
+}
+
+#[test]
+fn require_pragma_only_accepts_docblocks() {
+    let options = FormatOptions::default().with_require_pragma(true);
+    assert_eq!(
+        format_with_options("a.js", "// @format\nconst a=1\n", options.clone()),
+        "// @format\nconst a=1\n"
+    );
+    assert_eq!(
+        format_with_options(
+            "a.js",
+            "// license\n/** @format */\nconst a=1\n",
+            options.clone()
+        ),
+        "// license\n/** @format */\nconst a=1\n"
+    );
+    assert_eq!(
+        format_with_options("a.js", "/**/ /** @format */\nconst a=1\n", options.clone()),
+        "/**/ /** @format */\nconst a=1\n"
+    );
+    assert_eq!(
+        format_with_options("a.js", "/** @format */\nconst a=1\n", options),
+        "/** @format */\nconst a = 1;\n"
+    );
+}
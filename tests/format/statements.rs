@@ -0,0 +1,106 @@
+use crate::assert_format;
+
+#[test]
+fn switch() {
+    assert_format(
+        "a.js",
+        r#"switch (a) {
+  case 1:
+    foo();
+    break;
+
+  case 2:
+  case 3: {
+    bar();
+  }
+  default:
+}
+"#,
+        r#"switch (a) {
+	case 1:
+		foo();
+		break;
+
+	case 2:
+	case 3: {
+		bar();
+	}
+	default:
+}
+"#,
+    );
+}
+
+#[test]
+fn switch_comments() {
+    assert_format(
+        "a.js",
+        r#"switch (a) {
+  case 1:
+    foo();
+    bar(); // trailing
+  // falls through
+  case 2: // empty
+  case 3: {
+    x();
+  } // after block
+  default:
+    baz(); /* block */
+  // last comment
+}
+"#,
+        r#"switch (a) {
+	case 1:
+		foo();
+		bar(); // trailing
+	// falls through
+	case 2: // empty
+	case 3: {
+		x();
+	} // after block
+	default:
+		baz(); /* block */
+	// last comment
+}
+"#,
+    );
+}
+
+#[test]
+fn try_statement() {
+    assert_format(
+        "a.js",
+        r#"try { a() } catch { b() } finally { c() }
+try { a() } catch (e) { b(e) }
+"#,
+        r#"try {
+	a();
+} catch {
+	b();
+} finally {
+	c();
+}
+try {
+	a();
+} catch (e) {
+	b(e);
+}
+"#,
+    );
+}
+
+#[test]
+fn labeled_statement() {
+    assert_format(
+        "a.js",
+        r#"label: { foo(); break label; }
+lbl: x();
+"#,
+        r#"label: {
+	foo();
+	break label;
+}
+lbl: x();
+"#,
+    );
+}
@@ -0,0 +1,128 @@
+use crate::assert_format;
+
+#[test]
+fn suppressed_statements() {
+    assert_format(
+        "a.js",
+        r#"bar()
+
+// prettier-ignore
+baz(  1 )
+// oxc-formatter-ignore
+const   x = [1,2,3];
+"#,
+        r#"bar();
+
+// prettier-ignore
+baz(  1 )
+// oxc-formatter-ignore
+const   x = [1,2,3];
+"#,
+    );
+}
+
+#[test]
+fn trailing_suppression_comment_is_not_leading() {
+    assert_format(
+        "a.js",
+        "foo(); // prettier-ignore\nbar(1,2)\n",
+        "foo();\nbar(1, 2);\n",
+    );
+}
+
+#[test]
+fn suppressed_members_and_jsx() {
+    assert_format(
+        "a.jsx",
+        r#"const o = {
+  // prettier-ignore
+  key:   'value',
+  other:   'x',
+};
+class A {
+  // prettier-ignore
+  method( a,b ) { return a+b }
+}
+const el = <div
+  // prettier-ignore
+  style  =  {{a:1}}
+  id="x"
+>
+  {/* prettier-ignore */}
+  <span   a = "1"  />
+</div>;
+"#,
+        r#"const o = {
+	// prettier-ignore
+	key:   'value',
+	other: "x",
+};
+class A {
+	// prettier-ignore
+	method( a,b ) { return a+b }
+}
+const el = (
+	<div
+		// prettier-ignore
+		style  =  {{a:1}}
+		id="x"
+	>
+		{/* prettier-ignore */}
+		<span   a = "1"  />
+	</div>
+);
+"#,
+    );
+}
+
+#[test]
+fn ignored_ranges() {
+    assert_format(
+        "a.js",
+        r#"foo(  1 );
+// prettier-ignore-start
+const table = [
+  1, 0, 0,
+  0, 1, 0,
+];
+// prettier-ignore-end
+bar(  2 );
+"#,
+        r#"foo(1);
+// prettier-ignore-start
+const table = [
+  1, 0, 0,
+  0, 1, 0,
+];
+// prettier-ignore-end
+bar(2);
+"#,
+    );
+}
+
+#[test]
+fn unterminated_range_is_ignored_until_the_end() {
+    assert_format(
+        "a.js",
+        "foo(  1 );\n// prettier-ignore-start\nbar(  2 )\nbaz(  3 )\n",
+        "foo(1);\n// prettier-ignore-start\nbar(  2 )\nbaz(  3 )\n",
+    );
+}
+
+#[test]
+fn ignore_pragma() {
+    let source = "/** @format-ignore */\nfoo(  1 )\n";
+    assert_format("a.js", source, source);
+
+    let source = "// license\n// @format-ignore\nfoo(  1 )\n";
+    assert_format("a.js", source, source);
+}
+
+#[test]
+fn verbatim_text_keeps_unicode_line_separators() {
+    assert_format(
+        "a.js",
+        "for(;;){x=\"a\u{2028}b\"}\n// prettier-ignore\ny = \"c\u{2029}d\"\n",
+        "for(;;){x=\"a\u{2028}b\"}\n// prettier-ignore\ny = \"c\u{2029}d\"\n",
+    );
+}
@@ -0,0 +1,300 @@
+use oxc_formatter::{FormatOptions, Semicolons};
+
+use crate::{assert_format, assert_format_with_options};
+
+#[test]
+fn type_annotations() {
+    assert_format(
+        "a.ts",
+        r#"let a: string|number = 1;
+let b: Array<{x:number;y?:string}> = [];
+function f(x?: number, ...rest: string[]): void {}
+function isS(x: unknown): x is string { return true; }
+function assertS(x: unknown): asserts x is string {}
+"#,
+        r#"let a: string | number = 1;
+let b: Array<{ x: number; y?: string }> = [];
+function f(x?: number, ...rest: string[]): void {}
+function isS(x: unknown): x is string {
+	return true;
+}
+function assertS(x: unknown): asserts x is string {}
+"#,
+    );
+}
+
+#[test]
+fn type_forms() {
+    assert_format(
+        "a.ts",
+        r#"type F = (a: number, b: string) => boolean;
+type C = new (x: number) => Foo;
+let t: [a: string, b?: number, ...rest: boolean[]];
+let k: keyof typeof obj;
+let u: unique symbol;
+type M = { readonly [K in keyof T]?: T[K] };
+type Idx = Foo["bar"][number];
+type L = `prefix-${string}`;
+type I = import("./mod").Foo;
+type In = A & B & { c: string };
+type E<T> = T extends Array<infer U extends string> ? U : never;
+"#,
+        r#"type F = (a: number, b: string) => boolean;
+type C = new (x: number) => Foo;
+let t: [a: string, b?: number, ...rest: boolean[]];
+let k: keyof typeof obj;
+let u: unique symbol;
+type M = { readonly [K in keyof T]?: T[K] };
+type Idx = Foo["bar"][number];
+type L = `prefix-${string}`;
+type I = import("./mod").Foo;
+type In = A & B & { c: string };
+type E<T> = T extends Array<infer U extends string> ? U : never;
+"#,
+    );
+}
+
+#[test]
+fn long_unions_and_conditional_chains() {
+    assert_format(
+        "a.ts",
+        r#"type Long = "aaaaaaaaaaaaaa" | "bbbbbbbbbbbbbbbbbb" | "cccccccccccccccccccc" | "dddddddddddddddddd";
+type Cond<T> = T extends string ? "s" : T extends number ? "n" : never;
+"#,
+        r#"type Long =
+	| "aaaaaaaaaaaaaa"
+	| "bbbbbbbbbbbbbbbbbb"
+	| "cccccccccccccccccccc"
+	| "dddddddddddddddddd";
+type Cond<T> = T extends string
+	? "s"
+	: T extends number
+		? "n"
+		: never;
+"#,
+    );
+}
+
+#[test]
+fn interfaces_and_type_aliases() {
+    assert_format(
+        "a.ts",
+        r#"interface A extends B, C<D> { (x: number): string; new (x: number): A; [key: string]: any; method?(a: number): void; readonly prop: string }
+interface Empty {}
+type Alias<T = string> = Foo<T>;
+"#,
+        r#"interface A extends B, C<D> {
+	(x: number): string;
+	new (x: number): A;
+	[key: string]: any;
+	method?(a: number): void;
+	readonly prop: string;
+}
+interface Empty {}
+type Alias<T = string> = Foo<T>;
+"#,
+    );
+}
+
+#[test]
+fn interface_members_follow_semicolons() {
+    let options = FormatOptions::default().with_semicolons(Semicolons::AsNeeded);
+    assert_format_with_options(
+        "a.ts",
+        r#"interface A {
+  a: string; b(): void
+}
+type T = { a: string; b: number };
+"#,
+        r#"interface A {
+	a: string
+	b(): void
+}
+type T = { a: string; b: number }
+"#,
+        options,
+    );
+}
+
+#[test]
+fn enums_and_namespaces() {
+    assert_format(
+        "a.d.ts",
+        r#"enum Color { Red, Green = "g", Blue = 1 << 2 }
+const enum E { A }
+declare enum D { X = 1 }
+namespace NS.Inner { export const a = 1; }
+declare module "x" { export function f(): void; }
+declare global {
+  interface Window {
+    foo: string
+  }
+}
+"#,
+        r#"enum Color {
+	Red,
+	Green = "g",
+	Blue = 1 << 2,
+}
+const enum E {
+	A,
+}
+declare enum D {
+	X = 1,
+}
+namespace NS.Inner {
+	export const a = 1;
+}
+declare module "x" {
+	export function f(): void;
+}
+declare global {
+	interface Window {
+		foo: string;
+	}
+}
+"#,
+    );
+}
+
+#[test]
+fn generics_and_type_assertions() {
+    assert_format(
+        "a.ts",
+        r#"function g<T extends object = {}, U>(x: T): U { return foo<T>(x) as unknown as U; }
+const h = <T,>(x: T) => x;
+const v = new Map<string, number>();
+const s = { a: 1 } satisfies Foo;
+const n = maybe!.value!;
+const w = (x as any).y;
+"#,
+        r#"function g<T extends object = {}, U>(x: T): U {
+	return foo<T>(x) as unknown as U;
+}
+const h = <T>(x: T) => x;
+const v = new Map<string, number>();
+const s = { a: 1 } satisfies Foo;
+const n = maybe!.value!;
+const w = (x as any).y;
+"#,
+    );
+    assert_format(
+        "a.tsx",
+        "const h = <T,>(x: T) => x;\n",
+        "const h = <T,>(x: T) => x;\n",
+    );
+}
+
+#[test]
+fn modifiers_and_overloads() {
+    assert_format(
+        "a.ts",
+        r#"abstract class D extends E { abstract m(): void; protected override readonly x = 1; private static y?: number; }
+function over(a: string): void;
+function over(a: number): void;
+function over(a: any) {}
+"#,
+        r#"abstract class D extends E {
+	abstract m(): void;
+	protected override readonly x = 1;
+	private static y?: number;
+}
+function over(a: string): void;
+function over(a: number): void;
+function over(a: any) {}
+"#,
+    );
+}
+
+#[test]
+fn parameter_properties() {
+    assert_format(
+        "a.ts",
+        r#"class A { constructor(public a: string, protected readonly b = 2) {} }
+class B { constructor(private a: string) {} }
+class C { constructor(a: string, b: number) {} }
+"#,
+        r#"class A {
+	constructor(
+		public a: string,
+		protected readonly b = 2,
+	) {}
+}
+class B {
+	constructor(private a: string) {}
+}
+class C {
+	constructor(a: string, b: number) {}
+}
+"#,
+    );
+}
+
+#[test]
+fn string_literal_types() {
+    assert_format(
+        "a.ts",
+        r#"type T = 'a"b' | 'c\'d' | 'e\n' | "A";
+"#,
+        r#"type T = 'a"b' | "c\'d" | "e\n" | "A";
+"#,
+    );
+}
+
+#[test]
+fn declare_variables_and_string_enums() {
+    assert_format(
+        "a.d.ts",
+        r#"declare const u: unique symbol;
+export declare const y: string;
+declare let z, w: number;
+enum E { A = 'a"b', B = 'x', C = "it's" }
+"#,
+        r#"declare const u: unique symbol;
+export declare const y: string;
+declare let z, w: number;
+enum E {
+	A = 'a"b',
+	B = "x",
+	C = "it's",
+}
+"#,
+    );
+}
+
+#[test]
+fn class_properties_keep_semicolons_before_hazards() {
+    assert_format_with_options(
+        "a.ts",
+        r#"class C {
+	x = 1; [y] = 2
+	static; a = 1;
+	*gen() {}
+	b = 2; in
+	c = 3; static [z] = 1
+	get;
+	set = 1
+	d = 4; async *[k]() {}
+	e = 5
+}
+"#,
+        r#"class C {
+	x = 1;
+	[y] = 2
+	static;
+	a = 1;
+	*gen() {}
+	b = 2;
+	in
+	c = 3
+	static [z] = 1
+	get;
+	set = 1
+	d = 4
+	async *[k]() {}
+	e = 5
+}
+"#,
+        FormatOptions::default().with_semicolons(Semicolons::AsNeeded),
+    );
+}